@@ -6,6 +6,8 @@ use gdrust::macros::gdrust;
 #[gdrust(extends = Node)]
 #[signal(my_signal(arg1: F64, arg2: GodotString = "test".to_string()))]
 #[signal(simple_signal(arg:I64))]
+#[constant(MAX_TEST: i64 = 100)]
+#[constant(TestState { IDLE, RUNNING, DONE = 10 })]
 #[derive(Debug)]
 struct HelloWorld {
     #[export]
@@ -127,6 +129,19 @@ struct HelloWorld {
 
     #[export_flags_3d_render]
     layers_3d_render: u32,
+
+    #[export(as = f64, from = Health, into = Health::raw)]
+    #[default(Health(100.0))]
+    health: Health,
+}
+
+#[derive(Debug, Default)]
+struct Health(f64);
+
+impl Health {
+    fn raw(&self) -> f64 {
+        self.0
+    }
 }
 
 #[gdnative::methods]
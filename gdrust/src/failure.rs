@@ -0,0 +1,160 @@
+//! Controls what happens after `godot_panic!`, `godot_assert!` or one of the helpers in
+//! `unsafe_functions` fails. See the "Failure Policy" section of the crate docs.
+//!
+//! Any policy other than `Panic` still unwinds out of the failed call, since the helpers can not
+//! return a value they do not have. `guard` stops the unwinding before it reaches Godot.
+
+use gdnative::api::Engine;
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// What to do after an error has been printed to the Godot output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Panic. This prints the Rust panic message too, and is the default.
+    Panic,
+    /// Stop the current call without a Rust panic message. Use `guard` to recover from it.
+    LogAndAbortCall,
+    /// Behave like `LogAndAbortCall` while running in the editor, so a broken `tool` script only
+    /// shows an error in the editor. Behave like `Panic` in a running game.
+    PushError,
+}
+
+impl Policy {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Policy::LogAndAbortCall,
+            2 => Policy::PushError,
+            _ => Policy::Panic,
+        }
+    }
+
+    const fn to_u8(self) -> u8 {
+        match self {
+            Policy::Panic => 0,
+            Policy::LogAndAbortCall => 1,
+            Policy::PushError => 2,
+        }
+    }
+}
+
+#[cfg(feature = "policy-log-and-abort-call")]
+const DEFAULT_POLICY: Policy = Policy::LogAndAbortCall;
+#[cfg(all(
+    feature = "policy-push-error",
+    not(feature = "policy-log-and-abort-call")
+))]
+const DEFAULT_POLICY: Policy = Policy::PushError;
+#[cfg(not(any(feature = "policy-log-and-abort-call", feature = "policy-push-error")))]
+const DEFAULT_POLICY: Policy = Policy::Panic;
+
+static POLICY: AtomicU8 = AtomicU8::new(DEFAULT_POLICY.to_u8());
+
+thread_local! {
+    /// Set when a failure has already been printed, so `guard` does not print it again.
+    static LOGGED: Cell<bool> = Cell::new(false);
+}
+
+/// Sets what happens when a `gdrust` helper fails, for every thread.
+pub fn set_failure_policy(policy: Policy) {
+    POLICY.store(policy.to_u8(), Ordering::Relaxed);
+}
+
+/// The current failure policy. See `set_failure_policy`.
+pub fn failure_policy() -> Policy {
+    Policy::from_u8(POLICY.load(Ordering::Relaxed))
+}
+
+/// The payload of a call aborted by `LogAndAbortCall` or `PushError`.
+#[derive(Debug)]
+pub struct AbortCall;
+
+/// Leaves the current call according to the failure policy. `msg` must already be printed to the
+/// Godot output. This is used by `godot_panic!` and `godot_assert!`.
+#[doc(hidden)]
+#[track_caller]
+pub fn abort(msg: &str) -> ! {
+    LOGGED.with(|x| x.set(true));
+    let policy = failure_policy();
+    let quiet = match policy {
+        Policy::Panic => false,
+        Policy::LogAndAbortCall => true,
+        Policy::PushError => Engine::godot_singleton().is_editor_hint(),
+    };
+    if quiet {
+        resume_unwind(Box::new(AbortCall))
+    } else {
+        panic!("{}", msg)
+    }
+}
+
+/// Runs `f`, and returns `None` if it panics or is aborted instead of unwinding any further. Panics
+/// which were not printed by `gdrust` are printed to the Godot output. Use this for the body of
+/// exported methods:
+/// ```ignore
+/// #[export]
+/// fn _process(&mut self, owner: TRef<Node>, delta: f64) {
+///     gdrust::failure::guard(|| {
+///         owner.expect_node::<Label, _>("Label").set_text("Hello");
+///     });
+/// }
+/// ```
+///
+/// # GdScript Equivalent
+/// GdScript stops the function and prints the error on its own.
+pub fn guard<R>(f: impl FnOnce() -> R) -> Option<R> {
+    LOGGED.with(|x| x.set(false));
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(x) => Some(x),
+        Err(payload) => {
+            if !LOGGED.with(|x| x.replace(false)) {
+                gdnative::godot_error!("{}", panic_message(payload.as_ref()));
+            }
+            None
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("A call panicked")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{guard, panic_message, AbortCall, Policy, LOGGED};
+    use std::panic::resume_unwind;
+
+    #[test]
+    fn policy_round_trip() {
+        for policy in &[Policy::Panic, Policy::LogAndAbortCall, Policy::PushError] {
+            assert_eq!(Policy::from_u8(policy.to_u8()), *policy);
+        }
+    }
+
+    #[test]
+    fn guard_returns_value() {
+        assert_eq!(guard(|| 5), Some(5));
+    }
+
+    #[test]
+    fn guard_stops_aborted_call() {
+        let result = guard(|| {
+            LOGGED.with(|x| x.set(true));
+            resume_unwind(Box::new(AbortCall))
+        });
+        assert_eq!(result, None::<()>);
+    }
+
+    #[test]
+    fn panic_message_of_payload() {
+        assert_eq!(panic_message(&"static message"), "static message");
+        assert_eq!(panic_message(&"owned message".to_string()), "owned message");
+        assert_eq!(panic_message(&AbortCall), "A call panicked");
+    }
+}
@@ -0,0 +1,256 @@
+//! Typed input actions. Derive `InputActions` on an enum to use its variants instead of action
+//! name strings:
+//! ```ignore
+//! use gdrust::input::InputActions;
+//!
+//! #[derive(InputActions)]
+//! enum Action {
+//!     Jump,     // "jump"
+//!     MoveLeft, // "move_left"
+//!     #[action(name = "ui_accept")]
+//!     Confirm,
+//! }
+//!
+//! if Action::Jump.just_pressed() {
+//!     ...
+//! }
+//! ```
+//! Each variant is the snake case of its name, unless `name` is given. If a `project.godot` is found
+//! (see `preload!`), the names are checked against its input map when your crate compiles. Mark a
+//! variant `#[action(unchecked)]` if it is added at runtime, or the enum to skip the check entirely.
+
+use gdnative::api::{
+    Input, InputEvent, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputEventMouseMotion,
+};
+use gdnative::prelude::{Shared, SubClass, Vector2};
+use gdnative::TRef;
+
+pub use gdrust_macros::InputActions;
+
+/// An enum of input actions. Use `#[derive(InputActions)]` to implement this.
+pub trait InputActions: 'static + Sized {
+    /// The name of the action in the input map.
+    fn action_name(&self) -> &'static str;
+
+    /// Every action, in the order they are declared.
+    fn all() -> &'static [Self];
+
+    /// Returns `true` while the action is held down.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Input.is_action_pressed(action)
+    /// ```
+    fn is_pressed(&self) -> bool {
+        Input::godot_singleton().is_action_pressed(self.action_name())
+    }
+
+    /// Returns `true` on the frame the action is pressed.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Input.is_action_just_pressed(action)
+    /// ```
+    fn just_pressed(&self) -> bool {
+        Input::godot_singleton().is_action_just_pressed(self.action_name())
+    }
+
+    /// Returns `true` on the frame the action is released.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Input.is_action_just_released(action)
+    /// ```
+    fn just_released(&self) -> bool {
+        Input::godot_singleton().is_action_just_released(self.action_name())
+    }
+
+    /// How far the action is pressed, from `0.0` to `1.0`. This is only between them for analog
+    /// inputs, like a joystick axis or trigger.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Input.get_action_strength(action)
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    fn strength(&self) -> f32 {
+        Input::godot_singleton().get_action_strength(self.action_name()) as f32
+    }
+}
+
+/// Returns the strength of `positive` minus the strength of `negative`, from `-1.0` to `1.0`.
+/// Values closer to zero than `deadzone` are zero, and the rest are scaled so they still start at
+/// zero.
+///
+/// # GdScript Equivalent
+/// ```gdscript
+/// Input.get_axis(negative, positive)
+/// ```
+pub fn get_axis<A: InputActions>(negative: &A, positive: &A, deadzone: f32) -> f32 {
+    apply_deadzone(positive.strength() - negative.strength(), deadzone)
+}
+
+/// Returns a vector from four actions, such as movement. The deadzone is circular, and the vector is
+/// never longer than `1.0`, so moving diagonally is not faster.
+///
+/// # GdScript Equivalent
+/// ```gdscript
+/// Input.get_vector(negative_x, positive_x, negative_y, positive_y, deadzone)
+/// ```
+pub fn get_vector<A: InputActions>(
+    negative_x: &A,
+    positive_x: &A,
+    negative_y: &A,
+    positive_y: &A,
+    deadzone: f32,
+) -> Vector2 {
+    apply_deadzone_2d(
+        Vector2::new(
+            positive_x.strength() - negative_x.strength(),
+            positive_y.strength() - negative_y.strength(),
+        ),
+        deadzone,
+    )
+}
+
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    if value.abs() <= deadzone {
+        0.0
+    } else {
+        value.signum() * ((value.abs().min(1.0) - deadzone) / (1.0 - deadzone))
+    }
+}
+
+fn apply_deadzone_2d(vector: Vector2, deadzone: f32) -> Vector2 {
+    let length = vector.length();
+    if length <= deadzone {
+        Vector2::zero()
+    } else {
+        vector * (apply_deadzone(length, deadzone) / length)
+    }
+}
+
+pub trait InputEventExt {
+    /// Casts the event to an `InputEventKey`, if it is one.
+    fn as_key(&self) -> Option<TRef<InputEventKey>>;
+
+    /// Casts the event to an `InputEventMouseButton`, if it is one.
+    fn as_mouse_button(&self) -> Option<TRef<InputEventMouseButton>>;
+
+    /// Casts the event to an `InputEventMouseMotion`, if it is one.
+    fn as_mouse_motion(&self) -> Option<TRef<InputEventMouseMotion>>;
+
+    /// Casts the event to an `InputEventJoypadButton`, if it is one.
+    fn as_joypad_button(&self) -> Option<TRef<InputEventJoypadButton>>;
+
+    /// Casts the event to an `InputEventJoypadMotion`, if it is one.
+    fn as_joypad_motion(&self) -> Option<TRef<InputEventJoypadMotion>>;
+
+    /// Returns `true` if the event is part of `action`. This is used instead of
+    /// `InputEvent::is_action` when `InputEventExt` is in scope. Call
+    /// `InputEvent::is_action(&event, "name")` to use a string.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// event.is_action(action)
+    /// ```
+    fn is_action<A: InputActions>(&self, action: &A) -> bool;
+
+    /// Returns `true` if the event presses `action`. Echo events from holding a key down are
+    /// ignored.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// event.is_action_pressed(action)
+    /// ```
+    fn is_action_pressed<A: InputActions>(&self, action: &A) -> bool;
+
+    /// Returns `true` if the event releases `action`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// event.is_action_released(action)
+    /// ```
+    fn is_action_released<A: InputActions>(&self, action: &A) -> bool;
+}
+
+impl<'a, T: SubClass<InputEvent>> InputEventExt for TRef<'a, T> {
+    fn as_key(&self) -> Option<TRef<'a, InputEventKey, Shared>> {
+        self.upcast::<InputEvent>().cast()
+    }
+
+    fn as_mouse_button(&self) -> Option<TRef<'a, InputEventMouseButton, Shared>> {
+        self.upcast::<InputEvent>().cast()
+    }
+
+    fn as_mouse_motion(&self) -> Option<TRef<'a, InputEventMouseMotion, Shared>> {
+        self.upcast::<InputEvent>().cast()
+    }
+
+    fn as_joypad_button(&self) -> Option<TRef<'a, InputEventJoypadButton, Shared>> {
+        self.upcast::<InputEvent>().cast()
+    }
+
+    fn as_joypad_motion(&self) -> Option<TRef<'a, InputEventJoypadMotion, Shared>> {
+        self.upcast::<InputEvent>().cast()
+    }
+
+    fn is_action<A: InputActions>(&self, action: &A) -> bool {
+        InputEvent::is_action(&self.upcast::<InputEvent>(), action.action_name())
+    }
+
+    fn is_action_pressed<A: InputActions>(&self, action: &A) -> bool {
+        InputEvent::is_action_pressed(&self.upcast::<InputEvent>(), action.action_name(), false)
+    }
+
+    fn is_action_released<A: InputActions>(&self, action: &A) -> bool {
+        InputEvent::is_action_released(&self.upcast::<InputEvent>(), action.action_name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_deadzone, apply_deadzone_2d};
+    use gdnative::prelude::Vector2;
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.0001,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn deadzone_is_zero() {
+        assert_near(apply_deadzone(0.1, 0.2), 0.0);
+        assert_near(apply_deadzone(-0.2, 0.2), 0.0);
+    }
+
+    #[test]
+    fn deadzone_is_rescaled() {
+        assert_near(apply_deadzone(0.6, 0.2), 0.5);
+        assert_near(apply_deadzone(-0.6, 0.2), -0.5);
+        assert_near(apply_deadzone(1.0, 0.2), 1.0);
+    }
+
+    #[test]
+    fn deadzone_2d_is_circular() {
+        assert_eq!(
+            apply_deadzone_2d(Vector2::new(0.1, 0.1), 0.2),
+            Vector2::zero()
+        );
+        let vector = apply_deadzone_2d(Vector2::new(0.6, 0.0), 0.2);
+        assert_near(vector.x, 0.5);
+        assert_near(vector.y, 0.0);
+    }
+
+    #[test]
+    fn deadzone_2d_is_clamped() {
+        let vector = apply_deadzone_2d(Vector2::new(1.0, 1.0), 0.2);
+        assert_near(vector.length(), 1.0);
+        assert_near(vector.x, vector.y);
+    }
+}
@@ -120,8 +120,9 @@
 //!
 //! Everything should be implemented as defined in Godot's docs except for the following:
 //!
-//! 1. `#[no_export]` can be used to not export a variable. This should be used for all Rust-native
-//! types (doesn't implement `Export`) or if you want the variable to be "private".
+//! 1. `#[no_export]` can be used to not export a variable. This should be used for Rust-native
+//! types (doesn't implement `Export`) which can not be converted (see "Exporting Rust Types" below), or
+//! if you want the variable to be "private".
 //! 2. The 4.0 docs define `@export_node_path(Type1, Type2)` as a way to export a `NodePath` which
 //! only matches nodes with given types. This is partially implemented, but won't be finished until
 //! 4.0 because there is currently not export hint for NodePaths. You can currently include this
@@ -135,6 +136,30 @@
 //! I am guessing they will switch to some sort of `@export_array` style. Once that is finalized,
 //! adding it should be easy.
 //!
+//! #### Exporting Rust Types
+//! Fields whose type does not implement `Export`, like a newtype `Health(f32)`, can still be exported
+//! by converting them to a type Godot understands:
+//! ```
+//!# #[derive(Clone)]
+//!# pub struct Health(f64);
+//!# impl Health {
+//!#     fn raw(&self) -> f64 {
+//!#         self.0
+//!#     }
+//!# }
+//!# #[gdrust::macros::gdrust]
+//!# pub struct Test {
+//! #[export(as = f64, from = Health, into = Health::raw)]
+//! #[default(Health(100.0))]
+//! health: Health,
+//!# }
+//! ```
+//! The property is registered in Godot as `as`. When Godot sets the property, `from` is called with the
+//! Godot value to create the Rust value. When Godot reads the property, `into` is called with a
+//! reference to the Rust value. `from` and `into` may be any function or closure. If they are omitted,
+//! `From` is used in both directions (`into` also requires `Clone`). Hints like `#[export_range]` can be
+//! combined with a conversion, and apply to the `as` type.
+//!
 //! ### Default
 //! You may set a custom default value using the `#[default(value)]` annotation. If it is not defined,
 //! `Default::default()` is used.
@@ -163,8 +188,69 @@
 //! When a signal is exported, there will be a `const` with its name. Look at the `simple_signal`
 //! signal in the example below to see how it can be used.
 //!
+//! ## Exporting Constants
+//! GdScript classes can declare constants and enums that other scripts read, such as `Player.MAX_HP`.
+//! `gdrust` supports both with the `#[constant]` attribute:
+//! ```
+//!# use gdrust::macros::gdrust;
+//! #[gdrust]
+//! #[constant(MAX_HP: i64 = 100)]
+//! #[constant(State { IDLE, RUN, JUMP = 10 })]
+//! struct Class;
+//! ```
+//! This is the same as the following GdScript:
+//! ```gdscript
+//! const MAX_HP = 100
+//! enum State { IDLE, RUN, JUMP = 10 }
+//! ```
+//!
+//! Every constant becomes a `pub const` on the class, so Rust code can use `Class::MAX_HP`. Each enum
+//! variant becomes a `pub const` of type `i64`, prefixed with the name of the enum in upper snake
+//! case (`Class::STATE_IDLE`, `Class::STATE_RUN`, and `Class::STATE_JUMP`), so different enums can use
+//! the same variant names. Like GdScript, variants without a value are one more than the variant
+//! before them.
+//!
+//! NativeScript does not support real constants, so they are exposed to Godot as read-only properties.
+//! A value constant is a property with the constant's name, and an enum is a `Dictionary` property
+//! mapping each variant name to its value (`player.State.RUN` in GdScript). These properties are not
+//! stored in the scene and are hidden from the inspector. Since the value must be a Rust `const`, the
+//! type must be constructible at compile time, like an integer, float, `bool`, or `Vector2`.
+//!
+//! ## Viewing the Generated Code
+//! If the macro is not doing what you expect, you can look at the code it generates without installing
+//! `cargo-expand`. Add `debug_expand` to the macro:
+//! ```
+//!# use gdrust::macros::gdrust;
+//! #[gdrust(extends = gdnative::api::Node, debug_expand)]
+//! struct ClassName;
+//! ```
+//! The formatted expansion is written to `target/gdrust_expand/ClassName.rs`, in the `target` directory
+//! of the workspace (or `$CARGO_TARGET_DIR/gdrust_expand` if it is set). The file starts with a summary
+//! of every property with its hint and default, every signal, and every constant. If it can not be
+//! written, a warning is printed and the build continues.
+//!
+//! To dump every class at once, set the `GDRUST_EXPAND_DIR` environment variable instead. Each class
+//! will be written to that directory:
+//! ```text
+//! GDRUST_EXPAND_DIR=target/expanded cargo build
+//! ```
+//!
+//! ## Testing Without Godot
+//! Add `metadata` to the macro, as in `#[gdrust(extends = Node, metadata)]`, to also generate a
+//! `__gdrust_metadata()` function. It describes the same properties and signals as plain data, and
+//! does not need Godot, so the registration can be checked with `cargo test`. The function uses
+//! `gdrust::metadata`, so the crate needs a dependency on `gdrust` under that name:
+//! ```ignore
+//! #[test]
+//! fn health_is_exported() {
+//!     let health = HelloWorld::__gdrust_metadata().property("health").unwrap();
+//!     assert_eq!(health.default, Some("100"));
+//!     assert!(matches!(health.hint, PropertyHint::Range(_)));
+//! }
+//! ```
+//!
 //! ## Comprehensive Example
-//! This example should contain all possibilities for exporting properties and signals. It is used
+//! This example should contain all possibilities for exporting properties, signals, and constants. It is used
 //! for testing as well.
 //! ```
 //!use gdnative::api::{KinematicBody, Node, RigidBody, Texture};
@@ -175,6 +261,8 @@
 //! #[gdrust(extends = Node)]
 //! #[signal(my_signal(arg1: F64, arg2: GodotString = "test".to_string()))]
 //! #[signal(simple_signal(arg:I64))]
+//! #[constant(MAX_TEST: i64 = 100)]
+//! #[constant(TestState { IDLE, RUNNING, DONE = 10 })]
 //! #[derive(Debug)]
 //! struct HelloWorld {
 //!     #[export]
@@ -296,6 +384,19 @@
 //!
 //!     #[export_flags_3d_render]
 //!     layers_3d_render: u32,
+//!
+//!     #[export(as = f64, from = Health, into = Health::raw)]
+//!     #[default(Health(100.0))]
+//!     health: Health,
+//! }
+//!
+//! #[derive(Debug, Default)]
+//! struct Health(f64);
+//!
+//! impl Health {
+//!     fn raw(&self) -> f64 {
+//!         self.0
+//!     }
 //! }
 //!
 //! #[gdnative::methods]
@@ -368,6 +469,30 @@
 //! recover from a missing node at runtime? You will probably just `unwrap` anyways to appease the
 //! compiler.
 //!
+//! Scripts attached to other nodes can be reached the same way. Instead of getting the node and
+//! calling `cast_instance` and `map`:
+//! ```
+//!# use gdnative::api::KinematicBody;
+//!# use gdrust::unsafe_functions::node_ext::NodeExt;
+//!# #[gdrust::macros::gdrust(extends = KinematicBody)]
+//!# struct Player {
+//!#     health: i64,
+//!# }
+//!# fn test(owner: gdnative::TRef<gdnative::api::Node>) {
+//! owner.with_node_instance("Player", |player: &Player, _owner| player.health);
+//!# }
+//! ```
+//!
+//! Resources can be loaded like GdScript's `preload`. The path is checked when your crate compiles, by
+//! looking for the file next to the first `project.godot` above your crate (or in `GDRUST_PROJECT_DIR`),
+//! so a typo is a compile error instead of a crash. The type is inferred from the extension:
+//! ```ignore
+//! let enemy: Ref<PackedScene> = gdrust::preload!("res://scenes/enemy.tscn");
+//! let frames = gdrust::preload!("res://player/frames.tres" as SpriteFrames);
+//! ```
+//! The resource is loaded each time the line runs. Godot returns the same resource while anything still
+//! uses it, so keep the `Ref` to avoid loading it again.
+//!
 //! As a result, this is called `unsafe_functions` because it is unsafe in the eyes of rust, but
 //! when compared to GdScript, this is pretty normal and safe.
 //!
@@ -375,6 +500,56 @@
 //! [`gdnative-rust`'s memory model](https://docs.rs/gdnative/0.9.3/gdnative/struct.Ref.html). Once
 //! you do, you should have the right judgement on when to use these helper functions.
 //!
+//! # Input
+//! Input actions can be an enum instead of strings. Each variant is the snake case of its name, and the
+//! names are checked against the input map in `project.godot` when your crate compiles:
+//! ```ignore
+//! use gdrust::input::{get_vector, InputActions};
+//!
+//! #[derive(InputActions)]
+//! enum Action {
+//!     Jump,
+//!     MoveLeft,
+//!     MoveRight,
+//!     MoveUp,
+//!     MoveDown,
+//!     #[action(name = "ui_accept")]
+//!     Confirm,
+//! }
+//!
+//! if Action::Jump.just_pressed() {
+//!     // ...
+//! }
+//! let movement = get_vector(&Action::MoveLeft, &Action::MoveRight, &Action::MoveUp, &Action::MoveDown, 0.2);
+//! ```
+//! `InputEventExt` matches events against the same enum with `event.is_action_pressed(&Action::Jump)`.
+//!
+//! # Failure Policy
+//! By default, `godot_panic!`, `godot_assert!` and the helper functions panic when they fail. A panic
+//! in a `tool` script can take the editor down with it, so the failure policy can be changed when your
+//! library is initialized:
+//! ```ignore
+//! fn init(handle: InitHandle) {
+//!     gdrust::set_failure_policy(gdrust::Policy::PushError);
+//!     handle.add_class::<Player>();
+//! }
+//! ```
+//! - `Policy::Panic` panics after printing the error. This is the default.
+//! - `Policy::LogAndAbortCall` prints the error and stops the call, without a Rust panic message.
+//! - `Policy::PushError` behaves like `LogAndAbortCall` in the editor, and like `Panic` in the game.
+//!
+//! The default can be changed per build with the `policy-log-and-abort-call` and `policy-push-error`
+//! features. Wrap the body of an exported method in `gdrust::failure::guard` to stop there, and print
+//! any other panic to the Godot output:
+//! ```ignore
+//! #[export]
+//! fn _ready(&mut self, owner: TRef<Node>) {
+//!     gdrust::failure::guard(|| {
+//!         owner.expect_node::<Label, _>("Label").set_text("Ready");
+//!     });
+//! }
+//! ```
+//!
 //! # Compatibility
 //! Unfortunately, `gdrust` requires the `gdnative` dependency, and it can not be `pub use`d due
 //! to the way `gdnative`'s macros work. As as result, you must ensure you have a compatible version
@@ -383,10 +558,17 @@
 //! | `gdrust`  | `gdnative-rust` |
 //! |---------|----------|
 //! | `0.1.0` | `0.9.+`  |
+//! | `0.2.0` | `0.9.+`  |
 //!
 //! # Additional Reading
 //! - [Contributing](./CONTRIBUTING.md)
+//! - [Changelog](./CHANGELOG.md)
 //! - [Reasoning for this project](./docs/why_gdrust.md)
 //! - [FAQs](./docs/faq.md)
+pub use failure::{set_failure_policy, Policy};
 pub use gdrust_macros as macros;
+pub use gdrust_macros::preload;
+pub mod failure;
+pub mod input;
+pub mod metadata;
 pub mod unsafe_functions;
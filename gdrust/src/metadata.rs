@@ -0,0 +1,151 @@
+//! A plain data description of what a `#[gdrust]` class registers with Godot. Classes with
+//! `#[gdrust(metadata)]` get a `__gdrust_metadata()` function which returns it. Unlike
+//! `__register_properties_and_signals`, it does not need a running engine, so it can be checked with
+//! `cargo test`:
+//! ```ignore
+//! #[test]
+//! fn health_is_exported() {
+//!     let metadata = Player::__gdrust_metadata();
+//!     let health = metadata.property("health").unwrap();
+//!     assert_eq!(health.rust_type, "i64");
+//!     assert_eq!(health.default, Some("100"));
+//! }
+//! ```
+
+use gdnative::nativescript::PropertyUsage;
+
+/// Everything a class registers with Godot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassMetadata {
+    /// The name of the struct.
+    pub name: &'static str,
+    /// The class it `extends`, as written in `#[gdrust(extends = ...)]`.
+    pub base: &'static str,
+    /// The properties, in the order they are registered. Fields marked `#[no_export]` are not
+    /// registered, and constants are registered as properties with an empty usage.
+    pub properties: &'static [PropertyMetadata],
+    /// The signals, in the order they are declared.
+    pub signals: &'static [SignalMetadata],
+}
+
+impl ClassMetadata {
+    /// Finds the property called `name`.
+    pub fn property(&self, name: &str) -> Option<&'static PropertyMetadata> {
+        self.properties.iter().find(|x| x.name == name)
+    }
+
+    /// Finds the signal called `name`.
+    pub fn signal(&self, name: &str) -> Option<&'static SignalMetadata> {
+        self.signals.iter().find(|x| x.name == name)
+    }
+}
+
+/// A property registered with Godot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyMetadata {
+    /// The name shown in the inspector.
+    pub name: &'static str,
+    /// The type of the field, or of the constant.
+    pub rust_type: &'static str,
+    /// The type Godot sees. This is only different from `rust_type` when the field uses
+    /// `#[export(as = ...)]`.
+    pub export_type: &'static str,
+    /// How the property is shown in the inspector.
+    pub hint: PropertyHint,
+    /// The default value, as written in `#[default(...)]`, or the value of a constant. This is
+    /// `None` if there is no `#[default]`, in which case the field starts as
+    /// `Default::default()`. It is not evaluated, since that needs a running engine for most Godot
+    /// types.
+    pub default: Option<&'static str>,
+    /// Where Godot uses the property.
+    pub usage: PropertyUsage,
+}
+
+/// The hint of a property. These match the `#[export_*]` attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyHint {
+    None,
+    Range(RangeHint),
+    ExpRange(RangeHint),
+    Enum(&'static [&'static str]),
+    /// The filters of the file dialog.
+    File(&'static [&'static str]),
+    Dir,
+    /// The filters of the file dialog.
+    GlobalFile(&'static [&'static str]),
+    GlobalDir,
+    Multiline,
+    ColorNoAlpha,
+    /// The node types which can be selected.
+    NodePath(&'static [&'static str]),
+    Flags(&'static [&'static str]),
+    Layers2DPhysics,
+    Layers2DRender,
+    Layers3DPhysics,
+    Layers3DRender,
+}
+
+/// The arguments of `#[export_range]` and `#[export_exp_range]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeHint {
+    pub min: f64,
+    pub max: f64,
+    pub step: Option<f64>,
+    pub or_lesser: bool,
+    pub or_greater: bool,
+}
+
+/// A signal registered with Godot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalMetadata {
+    pub name: &'static str,
+    pub args: &'static [SignalArgMetadata],
+}
+
+/// An argument of a signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalArgMetadata {
+    pub name: &'static str,
+    /// The type, as written in `#[signal(...)]`.
+    pub ty: &'static str,
+    /// The default value, as written in `#[signal(...)]`.
+    pub default: Option<&'static str>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClassMetadata, PropertyHint, PropertyMetadata, SignalMetadata};
+    use gdnative::nativescript::PropertyUsage;
+
+    const METADATA: ClassMetadata = ClassMetadata {
+        name: "Player",
+        base: "Node",
+        properties: &[PropertyMetadata {
+            name: "health",
+            rust_type: "i64",
+            export_type: "i64",
+            hint: PropertyHint::None,
+            default: Some("100"),
+            usage: PropertyUsage::DEFAULT,
+        }],
+        signals: &[SignalMetadata {
+            name: "died",
+            args: &[],
+        }],
+    };
+
+    #[test]
+    fn find_property() {
+        assert_eq!(
+            METADATA.property("health").and_then(|x| x.default),
+            Some("100")
+        );
+        assert!(METADATA.property("speed").is_none());
+    }
+
+    #[test]
+    fn find_signal() {
+        assert_eq!(METADATA.signal("died").map(|x| x.args.len()), Some(0));
+        assert!(METADATA.signal("hit").is_none());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::godot_panic;
+use crate::unsafe_functions::node_ext::{cast_node, node_path, NodeLookupError};
+use gdnative::api::{Control, Font, GlobalConstants, StyleBox};
+use gdnative::prelude::{Color, Shared, SubClass};
+use gdnative::{Ref, TRef};
+use std::fmt::{Display, Formatter};
+
+pub trait ControlExt {
+    /// Gets the `Control` which gets focus when moving focus toward `margin`. This has an explicit
+    /// `unsafe` block, and can panic. The unsafe code is calling `assume_safe` on the neighbour.
+    /// # Panics
+    /// - If no neighbour is set for `margin`.
+    /// - If the neighbour can not be found, or is not a `Control`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_node(focus_neighbour_left)
+    /// ```
+    fn expect_focus_neighbour(&self, margin: Margin) -> TRef<Control>;
+
+    /// Moves the anchors to `preset`, and moves the margins so the `Control` keeps its size.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// set_anchors_and_margins_preset(preset, Control.PRESET_MODE_KEEP_SIZE)
+    /// ```
+    fn set_anchors_preset_keep_size(&self, preset: LayoutPreset);
+
+    /// Makes the `Control` fill its parent, and keeps it that way when the parent is resized.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// set_anchors_and_margins_preset(Control.PRESET_WIDE)
+    /// ```
+    fn fit_to_parent(&self);
+
+    /// Centers the `Control` in its parent without changing its size, and keeps it centered when
+    /// the parent is resized.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// set_anchors_and_margins_preset(Control.PRESET_CENTER, Control.PRESET_MODE_KEEP_SIZE)
+    /// ```
+    fn center_in_parent(&self);
+
+    /// Overrides the theme item `name` for this `Control`. `T` is the kind of theme item, which is
+    /// one of `Color`, `Font` or `StyleBox`.
+    ///
+    /// ```rust,ignore
+    /// label.theme_override::<Color>("font_color", Color::rgb(1.0, 0.0, 0.0));
+    /// label.theme_override::<Font>("font", font.upcast());
+    /// ```
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// add_color_override(name, value)
+    /// ```
+    fn theme_override<T: ThemeOverride>(&self, name: &str, value: T::Value);
+
+    /// Grabs focus at the end of the frame. This is needed when the `Control` was just added or
+    /// made visible, since `grab_focus` does nothing until it is. This has an explicit `unsafe`
+    /// block. The unsafe code is calling `call_deferred`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// call_deferred("grab_focus")
+    /// ```
+    fn grab_focus_deferred(&self);
+
+    /// Returns `true` if the mouse is over the global rect of the `Control`. Unlike checking the
+    /// position of the mouse against `rect_position`, this works when the parent is not at the
+    /// origin. This does not check whether another `Control` is on top.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_global_rect().has_point(get_global_mouse_position())
+    /// ```
+    fn is_mouse_over(&self) -> bool;
+}
+
+impl<'a, T: SubClass<Control>> ControlExt for TRef<'a, T> {
+    fn expect_focus_neighbour(&self, margin: Margin) -> TRef<'a, Control, Shared> {
+        let control = self.upcast::<Control>();
+        let path = control.focus_neighbour(margin.value());
+        if path.is_empty() {
+            godot_panic!(
+                "{} has no focus neighbour on the {} side",
+                node_path(&control),
+                margin
+            );
+        }
+        let path = path.to_string();
+        control
+            .get_node_or_null(path.as_str())
+            .ok_or_else(|| NodeLookupError::NotFound {
+                path,
+                from: node_path(&control),
+            })
+            .and_then(|x| unsafe { cast_node(x) })
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn set_anchors_preset_keep_size(&self, preset: LayoutPreset) {
+        self.upcast::<Control>().set_anchors_and_margins_preset(
+            preset.value(),
+            Control::PRESET_MODE_KEEP_SIZE,
+            0,
+        );
+    }
+
+    fn fit_to_parent(&self) {
+        self.upcast::<Control>().set_anchors_and_margins_preset(
+            Control::PRESET_WIDE,
+            Control::PRESET_MODE_MINSIZE,
+            0,
+        );
+    }
+
+    fn center_in_parent(&self) {
+        self.set_anchors_preset_keep_size(LayoutPreset::Center);
+    }
+
+    fn theme_override<O: ThemeOverride>(&self, name: &str, value: O::Value) {
+        O::add_override(&self.upcast::<Control>(), name, value);
+    }
+
+    fn grab_focus_deferred(&self) {
+        unsafe {
+            self.upcast::<Control>().call_deferred("grab_focus", &[]);
+        }
+    }
+
+    fn is_mouse_over(&self) -> bool {
+        let control = self.upcast::<Control>();
+        control
+            .get_global_rect()
+            .contains(control.get_global_mouse_position().to_point())
+    }
+}
+
+/// A kind of theme item which can be overridden with `ControlExt::theme_override`.
+pub trait ThemeOverride {
+    /// The value the theme item is overridden with.
+    type Value;
+
+    /// Overrides the theme item `name` of `control` with `value`.
+    fn add_override(control: &Control, name: &str, value: Self::Value);
+}
+
+impl ThemeOverride for Color {
+    type Value = Color;
+
+    fn add_override(control: &Control, name: &str, value: Self::Value) {
+        control.add_color_override(name, value);
+    }
+}
+
+impl ThemeOverride for Font {
+    type Value = Ref<Font, Shared>;
+
+    fn add_override(control: &Control, name: &str, value: Self::Value) {
+        control.add_font_override(name, value);
+    }
+}
+
+impl ThemeOverride for StyleBox {
+    type Value = Ref<StyleBox, Shared>;
+
+    fn add_override(control: &Control, name: &str, value: Self::Value) {
+        control.add_stylebox_override(name, value);
+    }
+}
+
+/// A side of a `Control`, used for margins, anchors and focus neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Margin {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+impl Margin {
+    /// The value Godot uses for this margin.
+    pub fn value(self) -> i64 {
+        match self {
+            Margin::Left => GlobalConstants::MARGIN_LEFT,
+            Margin::Top => GlobalConstants::MARGIN_TOP,
+            Margin::Right => GlobalConstants::MARGIN_RIGHT,
+            Margin::Bottom => GlobalConstants::MARGIN_BOTTOM,
+        }
+    }
+}
+
+impl Display for Margin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Margin::Left => "left",
+            Margin::Top => "top",
+            Margin::Right => "right",
+            Margin::Bottom => "bottom",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Where a `Control` is anchored in its parent. These are the presets in the editor's layout menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutPreset {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    CenterLeft,
+    CenterTop,
+    CenterRight,
+    CenterBottom,
+    Center,
+    LeftWide,
+    TopWide,
+    RightWide,
+    BottomWide,
+    VCenterWide,
+    HCenterWide,
+    Wide,
+}
+
+impl LayoutPreset {
+    /// The value Godot uses for this preset.
+    pub fn value(self) -> i64 {
+        match self {
+            LayoutPreset::TopLeft => Control::PRESET_TOP_LEFT,
+            LayoutPreset::TopRight => Control::PRESET_TOP_RIGHT,
+            LayoutPreset::BottomLeft => Control::PRESET_BOTTOM_LEFT,
+            LayoutPreset::BottomRight => Control::PRESET_BOTTOM_RIGHT,
+            LayoutPreset::CenterLeft => Control::PRESET_CENTER_LEFT,
+            LayoutPreset::CenterTop => Control::PRESET_CENTER_TOP,
+            LayoutPreset::CenterRight => Control::PRESET_CENTER_RIGHT,
+            LayoutPreset::CenterBottom => Control::PRESET_CENTER_BOTTOM,
+            LayoutPreset::Center => Control::PRESET_CENTER,
+            LayoutPreset::LeftWide => Control::PRESET_LEFT_WIDE,
+            LayoutPreset::TopWide => Control::PRESET_TOP_WIDE,
+            LayoutPreset::RightWide => Control::PRESET_RIGHT_WIDE,
+            LayoutPreset::BottomWide => Control::PRESET_BOTTOM_WIDE,
+            LayoutPreset::VCenterWide => Control::PRESET_VCENTER_WIDE,
+            LayoutPreset::HCenterWide => Control::PRESET_HCENTER_WIDE,
+            LayoutPreset::Wide => Control::PRESET_WIDE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Margin;
+
+    #[test]
+    fn margin_display() {
+        assert_eq!(Margin::Left.to_string(), "left");
+        assert_eq!(Margin::Bottom.to_string(), "bottom");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::unsafe_functions::resource_loader_ext::{cast_resource, LoadError, ResourceLoaderExt};
+use gdnative::api::ResourceInteractiveLoader;
+use gdnative::prelude::{GodotError, GodotString, Resource, ResourceLoader, SubClass};
+use gdnative::ref_kind::RefCounted;
+use gdnative::{GodotObject, Ref};
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// A resource being loaded in steps. See `ResourceLoaderExt::load_interactive_typed`.
+pub struct InteractiveLoad<T> {
+    path: String,
+    loader: Ref<ResourceInteractiveLoader>,
+    state: LoadState,
+    _marker: PhantomData<fn() -> T>,
+}
+
+enum LoadState {
+    Loading,
+    Loaded,
+    Failed(LoadError),
+}
+
+impl<T> InteractiveLoad<T>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    pub(crate) fn new(path: String, loader: Ref<ResourceInteractiveLoader>) -> Self {
+        Self {
+            path,
+            loader,
+            state: LoadState::Loading,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The path of the resource being loaded.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// How much of the resource has been loaded, from `0.0` to `1.0`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// float(loader.get_stage()) / loader.get_stage_count()
+    /// ```
+    pub fn progress(&self) -> f32 {
+        if !matches!(self.state, LoadState::Loading) {
+            return 1.0;
+        }
+        let loader = unsafe { self.loader.assume_safe() };
+        let count = loader.get_stage_count();
+        if count <= 0 {
+            0.0
+        } else {
+            loader.get_stage() as f32 / count as f32
+        }
+    }
+
+    /// Returns `true` once the resource has been loaded, or loading it has failed.
+    pub fn is_done(&self) -> bool {
+        !matches!(self.state, LoadState::Loading)
+    }
+
+    /// Loads as many steps as fit in `budget_ms` milliseconds, and returns `is_done()`. At least
+    /// one step is loaded each call, so this always makes progress.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var deadline = OS.get_ticks_msec() + budget_ms
+    /// while OS.get_ticks_msec() < deadline:
+    ///     if loader.poll() != OK:
+    ///         break
+    /// ```
+    pub fn poll(&mut self, budget_ms: u64) -> bool {
+        self.poll_until(Instant::now() + Duration::from_millis(budget_ms))
+    }
+
+    pub(crate) fn poll_until(&mut self, deadline: Instant) -> bool {
+        while !self.is_done() {
+            self.step();
+            if Instant::now() >= deadline {
+                break;
+            }
+        }
+        self.is_done()
+    }
+
+    /// Loads the rest of the resource, blocking until it is done, and returns it.
+    ///
+    /// # Errors
+    /// - `LoadError::WrongType`: If the loaded resource is not a `T`.
+    /// - `LoadError::Failed`: If the resource could not be loaded.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// loader.wait()
+    /// loader.get_resource() as T
+    /// ```
+    pub fn finish(mut self) -> Result<Ref<T>, LoadError> {
+        while !self.is_done() {
+            self.step();
+        }
+        match self.state {
+            LoadState::Failed(e) => Err(e),
+            _ => {
+                let resource = unsafe { self.loader.assume_safe() }
+                    .get_resource()
+                    .ok_or_else(|| LoadError::Failed {
+                        path: self.path.clone(),
+                    })?;
+                cast_resource(resource, &self.path)
+            }
+        }
+    }
+
+    fn step(&mut self) {
+        self.state = match unsafe { self.loader.assume_safe() }.poll() {
+            Ok(()) => LoadState::Loading,
+            // Godot reports that loading has finished with `ERR_FILE_EOF`.
+            Err(GodotError::FileEof) => LoadState::Loaded,
+            Err(_) => LoadState::Failed(LoadError::Failed {
+                path: self.path.clone(),
+            }),
+        };
+    }
+}
+
+/// Loads many resources in steps, and reports their combined progress for a loading screen. Use
+/// `Resource` as `T` to load resources of different types.
+///
+/// ```rust,ignore
+/// let mut queue = LoadQueue::<PackedScene>::new();
+/// queue.push("res://levels/level_1.tscn");
+/// queue.push("res://levels/level_2.tscn");
+/// // Every frame:
+/// if queue.poll(8) {
+///     let levels = queue.finish();
+/// } else {
+///     progress_bar.set_value(queue.progress() as f64);
+/// }
+/// ```
+pub struct LoadQueue<T> {
+    loads: Vec<Result<InteractiveLoad<T>, LoadError>>,
+}
+
+impl<T> LoadQueue<T>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    pub fn new() -> Self {
+        Self { loads: Vec::new() }
+    }
+
+    /// Starts loading the resource at `path`. If it can not be loaded, the error is returned from
+    /// `finish` in its place.
+    pub fn push(&mut self, path: impl Into<GodotString>) {
+        let loader = ResourceLoader::godot_singleton();
+        self.loads.push(loader.load_interactive_typed(path));
+    }
+
+    /// The number of resources in the queue.
+    pub fn len(&self) -> usize {
+        self.loads.len()
+    }
+
+    /// Returns `true` if no resources have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.loads.is_empty()
+    }
+
+    /// The average progress of every resource in the queue, from `0.0` to `1.0`. Resources which
+    /// failed to load count as done.
+    pub fn progress(&self) -> f32 {
+        if self.loads.is_empty() {
+            return 1.0;
+        }
+        let total: f32 = self
+            .loads
+            .iter()
+            .map(|x| x.as_ref().map_or(1.0, |x| x.progress()))
+            .sum();
+        total / self.loads.len() as f32
+    }
+
+    /// Returns `true` once every resource has been loaded or has failed.
+    pub fn is_done(&self) -> bool {
+        self.loads
+            .iter()
+            .all(|x| x.as_ref().map_or(true, |x| x.is_done()))
+    }
+
+    /// Loads the resources in order for up to `budget_ms` milliseconds, and returns `is_done()`.
+    pub fn poll(&mut self, budget_ms: u64) -> bool {
+        let deadline = Instant::now() + Duration::from_millis(budget_ms);
+        for load in self.loads.iter_mut().flatten() {
+            if !load.poll_until(deadline) {
+                break;
+            }
+        }
+        self.is_done()
+    }
+
+    /// Loads the rest of the resources, blocking until they are done, and returns them in the
+    /// order they were pushed.
+    pub fn finish(self) -> Vec<Result<Ref<T>, LoadError>> {
+        self.loads
+            .into_iter()
+            .map(|x| x.and_then(InteractiveLoad::finish))
+            .collect()
+    }
+}
+
+impl<T> Default for LoadQueue<T>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
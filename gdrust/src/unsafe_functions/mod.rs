@@ -2,24 +2,37 @@
 //! `gdnative-rust`'s memory model and read the documentation on these methods. These methods are
 //! only really unsafe if you don't understand what they are doing.
 
+pub mod control_ext;
+pub mod interactive_load;
 pub mod node2_ext;
 pub mod node_ext;
+pub mod node_iter;
 pub mod object_ext;
 pub mod option_ext;
 pub mod packed_scene_ext;
+pub mod physics_ext;
 pub mod resource_loader_ext;
 pub mod result_ext;
+pub mod scene_tree_ext;
 pub mod spatial_ext;
+pub mod timer_ext;
+pub mod tween;
 pub mod vector2_ext;
 pub mod vector3_ext;
 
-/// Same functionality as `panic!()`, but also outputs to the godot output.
+use crate::unsafe_functions::node_ext::node_path;
+use crate::unsafe_functions::object_ext::attached_script;
+use gdnative::prelude::Node;
+use std::panic::Location;
+
+/// Same functionality as `panic!()`, but also outputs to the godot output. What happens after the
+/// output depends on the failure policy. See `set_failure_policy`.
 #[macro_export]
 macro_rules! godot_panic {
     ($($args:tt)*) => {
         {
             gdnative::godot_error!($($args)*);
-            panic!($($args)*);
+            $crate::failure::abort(&format!($($args)*));
         }
     }
 }
@@ -30,13 +43,13 @@ macro_rules! godot_assert {
     ($condition:expr $(,)?) => {
         if !$condition {
             gdnative::godot_error!("Assertion error: {}", stringify!($condition));
-            panic!("Assertion error: {}", stringify!($condition));
+            $crate::failure::abort(&format!("Assertion error: {}", stringify!($condition)));
         }
     };
     ($condition:expr, $($args:tt)*) => {
         if !$condition {
             gdnative::godot_error!($($args)*);
-            panic!($($args)*);
+            $crate::failure::abort(&format!($($args)*));
         }
     };
 }
@@ -48,19 +61,185 @@ macro_rules! godot_debug_assert {
 
         if cfg!(debug_assertions) && !$condition {
             gdnative::godot_error!("Assertion error: {}", stringify!($condition));
-            panic!("Assertion error: {}", stringify!($condition));
+            $crate::failure::abort(&format!("Assertion error: {}", stringify!($condition)));
         }
     };
     ($condition:expr, $($args:tt)*) => {
         if cfg!(debug_assertions) && !$condition {
             gdnative::godot_error!($($args)*);
-            panic!($($args)*);
+            $crate::failure::abort(&format!($($args)*));
+        }
+    };
+}
+
+/// Same functionality as `assert_eq!()`, but also outputs to the godot output. Both values are
+/// printed with `Debug`.
+#[macro_export]
+macro_rules! godot_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::godot_panic!(
+                        "Assertion error: `left == right`\n  left: `{:?}`\n right: `{:?}`",
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($args:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::godot_panic!(
+                        "Assertion error: `left == right`: {}\n  left: `{:?}`\n right: `{:?}`",
+                        format_args!($($args)+),
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Same functionality as `assert_ne!()`, but also outputs to the godot output. Both values are
+/// printed with `Debug`.
+#[macro_export]
+macro_rules! godot_assert_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    $crate::godot_panic!(
+                        "Assertion error: `left != right`\n  left: `{:?}`\n right: `{:?}`",
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($args:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    $crate::godot_panic!(
+                        "Assertion error: `left != right`: {}\n  left: `{:?}`\n right: `{:?}`",
+                        format_args!($($args)+),
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Same functionality as `debug_assert_eq!()`, but also outputs to the godot output.
+#[macro_export]
+macro_rules! godot_debug_assert_eq {
+    ($($args:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::godot_assert_eq!($($args)*);
+        }
+    };
+}
+
+/// Same functionality as `unreachable!()`, but also outputs to the godot output.
+#[macro_export]
+macro_rules! godot_unreachable {
+    () => {
+        $crate::godot_panic!("Entered unreachable code")
+    };
+    ($($args:tt)+) => {
+        $crate::godot_panic!("Entered unreachable code: {}", format_args!($($args)+))
+    };
+}
+
+/// Same functionality as `todo!()`, but also outputs to the godot output.
+#[macro_export]
+macro_rules! godot_todo {
+    () => {
+        $crate::godot_panic!("Not yet implemented")
+    };
+    ($($args:tt)+) => {
+        $crate::godot_panic!("Not yet implemented: {}", format_args!($($args)+))
+    };
+}
+
+/// Same functionality as `unimplemented!()`, but also outputs to the godot output.
+#[macro_export]
+macro_rules! godot_unimplemented {
+    () => {
+        $crate::godot_panic!("Not implemented")
+    };
+    ($($args:tt)+) => {
+        $crate::godot_panic!("Not implemented: {}", format_args!($($args)+))
+    };
+}
+
+/// Same functionality as `godot_warn!()`, but only outputs the first time this line runs. Use this
+/// in code which runs every frame, so the output is not flooded with the same warning.
+#[macro_export]
+macro_rules! godot_warn_once {
+    ($($args:tt)*) => {
+        {
+            static WARNED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+            if !WARNED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                gdnative::godot_warn!($($args)*);
+            }
         }
     };
 }
 
+/// Same as `godot_panic!`, but reports the location of the caller instead of the line in `gdrust`.
+/// Every function between the user's code and this one needs `#[track_caller]`.
+#[track_caller]
+pub(crate) fn panic_at_caller(msg: &str) -> ! {
+    let location = Location::caller();
+    gdnative::godot_error!("{} at {}", msg, location);
+    crate::failure::abort(msg);
+}
+
+/// Describes `node` for an error message, with its path, class and script.
+pub(crate) fn node_context(node: &Node) -> String {
+    describe_node(
+        &node_path(node),
+        &node.get_class().to_string(),
+        attached_script(node).as_deref(),
+    )
+}
+
+fn describe_node(path: &str, class: &str, script: Option<&str>) -> String {
+    match script {
+        Some(script) => format!("{} ({} with {} attached)", path, class, script),
+        None => format!("{} ({})", path, class),
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use super::describe_node;
+
+    #[test]
+    fn describe_node_with_script() {
+        assert_eq!(
+            describe_node("/root/Main/Player", "KinematicBody", Some("Player")),
+            "/root/Main/Player (KinematicBody with Player attached)"
+        );
+    }
+
+    #[test]
+    fn describe_node_without_script() {
+        assert_eq!(
+            describe_node("/root/Main/Camera", "Camera", None),
+            "/root/Main/Camera (Camera)"
+        );
+    }
+
     #[test]
     fn godot_assert_true() {
         godot_assert!(true)
@@ -80,4 +259,44 @@ mod test {
     fn godot_debug_assert_message_true() {
         godot_debug_assert!(true, "this should not {}", "happen")
     }
+
+    #[test]
+    fn godot_assert_eq_equal() {
+        godot_assert_eq!(1, 1)
+    }
+
+    #[test]
+    fn godot_assert_eq_message_equal() {
+        godot_assert_eq!(1, 1, "this should not {}", 2)
+    }
+
+    #[test]
+    fn godot_assert_ne_not_equal() {
+        godot_assert_ne!(1, 2)
+    }
+
+    #[test]
+    fn godot_assert_ne_message_not_equal() {
+        godot_assert_ne!(1, 2, "this should not {}", 2)
+    }
+
+    #[test]
+    fn godot_debug_assert_eq_equal() {
+        godot_debug_assert_eq!(1, 1)
+    }
+
+    /// Only the last arm runs. The others check that the macros are `!`, like the ones they mirror.
+    fn never_arms(x: i64) -> i64 {
+        match x {
+            0 => godot_unreachable!(),
+            1 => godot_todo!(),
+            2 => godot_unimplemented!("{}", x),
+            _ => x,
+        }
+    }
+
+    #[test]
+    fn godot_unreachable_todo_unimplemented_are_never() {
+        assert_eq!(never_arms(3), 3);
+    }
 }
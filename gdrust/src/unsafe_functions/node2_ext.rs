@@ -1,5 +1,5 @@
 use crate::unsafe_functions::vector2_ext::Vector2Ext;
-use gdnative::api::Node2D;
+use gdnative::api::{AnimatedSprite, Node2D, Sprite};
 use gdnative::object::SubClass;
 use gdnative::prelude::{Angle, Vector2, Vector2Godot};
 use gdnative::TRef;
@@ -9,7 +9,7 @@ pub trait Node2DExt {
     ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// Node2D.global_transform.xform(Vector2(0, 1))
+    /// Vector2.UP.rotated(Node2D.global_rotation)
     /// ```
     fn global_up(&self) -> Vector2;
 
@@ -17,15 +17,31 @@ pub trait Node2DExt {
     ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// Node2D.transform.xform(Vector2(0, 1))
+    /// Vector2.UP.rotated(Node2D.rotation)
     /// ```
     fn up(&self) -> Vector2;
 
+    /// Returns the unit vector representing down relative to the `Node2D`'s global orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Vector2.DOWN.rotated(Node2D.global_rotation)
+    /// ```
+    fn global_down(&self) -> Vector2;
+
+    /// Returns the unit vector representing down relative to the `Node2D`'s local orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Vector2.DOWN.rotated(Node2D.rotation)
+    /// ```
+    fn down(&self) -> Vector2;
+
     /// Returns the unit vector representing right relative to the `Node2D`'s global orientation.
     ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// Node2D.global_transform.xform(Vector2(1, 0))
+    /// Vector2.RIGHT.rotated(Node2D.global_rotation)
     /// ```
     fn global_right(&self) -> Vector2;
 
@@ -33,30 +49,277 @@ pub trait Node2DExt {
     ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// Node2D.transform.xform(Vector2(1, 0))
+    /// Vector2.RIGHT.rotated(Node2D.rotation)
     /// ```
     fn right(&self) -> Vector2;
+
+    /// Returns the unit vector representing left relative to the `Node2D`'s global orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Vector2.LEFT.rotated(Node2D.global_rotation)
+    /// ```
+    fn global_left(&self) -> Vector2;
+
+    /// Returns the unit vector representing left relative to the `Node2D`'s local orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Vector2.LEFT.rotated(Node2D.rotation)
+    /// ```
+    fn left(&self) -> Vector2;
+
+    /// Moves the `Node2D` to `position` in global space, without changing its global rotation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var rotation = node.global_rotation
+    /// node.global_position = position
+    /// node.global_rotation = rotation
+    /// ```
+    fn set_global_position_keep_rotation(&self, position: Vector2);
+
+    /// Sets the rotation of the `Node2D` relative to its parent.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.rotation = angle
+    /// ```
+    fn set_local_rotation(&self, angle: Angle);
+
+    /// Sets the global rotation of the `Node2D`. This takes an `Angle` instead of radians, so it
+    /// is used instead of `Node2D::set_global_rotation` when `Node2DExt` is in scope. Call
+    /// `Node2D::set_global_rotation(&node, radians)` to set it in radians.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.global_rotation = angle
+    /// ```
+    fn set_global_rotation(&self, angle: Angle);
+
+    /// Rotates the `Node2D` toward `target` by at most `max_angle`, taking the shortest way
+    /// around. Like `look_at`, the `Node2D` faces `target` with its right vector. Call this every
+    /// frame with `speed * delta` to turn at a fixed speed. Returns `true` once it faces `target`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var angle = node.get_angle_to(target)
+    /// node.rotate(clamp(angle, -max_angle, max_angle))
+    /// ```
+    fn look_at_smooth(&self, target: Vector2, max_angle: Angle) -> bool;
+
+    /// Returns the unit vector pointing from the global position of this `Node2D` to the global
+    /// position of `other`. Returns zero if they are at the same place.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.global_position.direction_to(other.global_position)
+    /// ```
+    fn direction_to<N: SubClass<Node2D>>(&self, other: TRef<N>) -> Vector2;
+
+    /// Returns the distance between the global positions of this `Node2D` and `other`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.global_position.distance_to(other.global_position)
+    /// ```
+    fn distance_to<N: SubClass<Node2D>>(&self, other: TRef<N>) -> f32;
+
+    /// Converts a direction in global space to the `Node2D`'s local space. Unlike `to_local`, this
+    /// ignores the position, so it is for directions and velocities rather than points. Returns
+    /// zero if the `Node2D` is scaled to zero.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.global_transform.basis_xform_inv(direction)
+    /// ```
+    fn to_local_vec(&self, direction: Vector2) -> Vector2;
+
+    /// Converts a direction in the `Node2D`'s local space to global space. Unlike `to_global`, this
+    /// ignores the position, so it is for directions and velocities rather than points.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// node.global_transform.basis_xform(direction)
+    /// ```
+    fn to_global_vec(&self, direction: Vector2) -> Vector2;
+
+    /// Returns the direction a sprite is facing in global space. This is `global_right`, or
+    /// `global_left` if the `Node2D` is a `Sprite` or `AnimatedSprite` with `flip_h` set.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// Vector2.LEFT.rotated(node.global_rotation) if node.flip_h else Vector2.RIGHT.rotated(node.global_rotation)
+    /// ```
+    fn flip_h_aware_forward(&self) -> Vector2;
 }
 
-#[allow(clippy::cast_possible_truncation)]
 impl<'a, Class: SubClass<Node2D>> Node2DExt for TRef<'a, Class> {
     fn global_up(&self) -> Vector2 {
-        Vector2::up().rotated(Angle::radians(self.upcast::<Node2D>().rotation() as f32))
+        Vector2::up().rotated(global_angle(self.upcast()))
     }
 
     fn up(&self) -> Vector2 {
-        Vector2::up().rotated(Angle::radians(
-            self.upcast::<Node2D>().global_rotation() as f32
-        ))
+        Vector2::up().rotated(local_angle(self.upcast()))
+    }
+
+    fn global_down(&self) -> Vector2 {
+        Vector2::down().rotated(global_angle(self.upcast()))
+    }
+
+    fn down(&self) -> Vector2 {
+        Vector2::down().rotated(local_angle(self.upcast()))
     }
 
     fn global_right(&self) -> Vector2 {
-        Vector2::right().rotated(Angle::radians(self.upcast::<Node2D>().rotation() as f32))
+        Vector2::right().rotated(global_angle(self.upcast()))
     }
 
     fn right(&self) -> Vector2 {
-        Vector2::right().rotated(Angle::radians(
-            self.upcast::<Node2D>().global_rotation() as f32
-        ))
+        Vector2::right().rotated(local_angle(self.upcast()))
+    }
+
+    fn global_left(&self) -> Vector2 {
+        Vector2::left().rotated(global_angle(self.upcast()))
+    }
+
+    fn left(&self) -> Vector2 {
+        Vector2::left().rotated(local_angle(self.upcast()))
+    }
+
+    fn set_global_position_keep_rotation(&self, position: Vector2) {
+        let node = self.upcast::<Node2D>();
+        let rotation = node.global_rotation();
+        node.set_global_position(position);
+        Node2D::set_global_rotation(&node, rotation);
+    }
+
+    fn set_local_rotation(&self, angle: Angle) {
+        self.upcast::<Node2D>()
+            .set_rotation(f64::from(angle.radians));
+    }
+
+    fn set_global_rotation(&self, angle: Angle) {
+        // Calling it as a method would call this function again.
+        Node2D::set_global_rotation(&self.upcast::<Node2D>(), f64::from(angle.radians));
+    }
+
+    fn look_at_smooth(&self, target: Vector2, max_angle: Angle) -> bool {
+        let node = self.upcast::<Node2D>();
+        let direction = target - node.global_position();
+        if direction.square_length() < EPSILON {
+            return true;
+        }
+        let current = global_angle(node);
+        let remaining = current.angle_to(direction.angle_from_x_axis());
+        let step = clamp_angle(remaining, max_angle);
+        self.set_global_rotation((current + step).signed());
+        (remaining - step).radians.abs() < EPSILON
+    }
+
+    fn direction_to<N: SubClass<Node2D>>(&self, other: TRef<N>) -> Vector2 {
+        let direction =
+            other.upcast::<Node2D>().global_position() - self.upcast::<Node2D>().global_position();
+        if direction.square_length() < EPSILON {
+            Vector2::zero()
+        } else {
+            direction.normalize()
+        }
+    }
+
+    fn distance_to<N: SubClass<Node2D>>(&self, other: TRef<N>) -> f32 {
+        (other.upcast::<Node2D>().global_position() - self.upcast::<Node2D>().global_position())
+            .length()
+    }
+
+    fn to_local_vec(&self, direction: Vector2) -> Vector2 {
+        self.upcast::<Node2D>()
+            .get_global_transform()
+            .inverse()
+            .map_or_else(Vector2::zero, |x| x.transform_vector(direction))
+    }
+
+    fn to_global_vec(&self, direction: Vector2) -> Vector2 {
+        self.upcast::<Node2D>()
+            .get_global_transform()
+            .transform_vector(direction)
+    }
+
+    fn flip_h_aware_forward(&self) -> Vector2 {
+        let node = self.upcast::<Node2D>();
+        let flipped = node
+            .cast::<Sprite>()
+            .map(|x| x.is_flipped_h())
+            .or_else(|| node.cast::<AnimatedSprite>().map(|x| x.is_flipped_h()))
+            .unwrap_or(false);
+        if flipped {
+            self.global_left()
+        } else {
+            self.global_right()
+        }
+    }
+}
+
+/// Lengths and angles below this are treated as zero. This is the same as Godot's `CMP_EPSILON`.
+const EPSILON: f32 = 0.00001;
+
+/// Limits `angle` to `-max..=max`.
+fn clamp_angle(angle: Angle, max: Angle) -> Angle {
+    let max = max.radians.abs();
+    Angle::radians(angle.radians.max(-max).min(max))
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn global_angle(node: TRef<Node2D>) -> Angle {
+    Angle::radians(node.global_rotation() as f32)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn local_angle(node: TRef<Node2D>) -> Angle {
+    Angle::radians(node.rotation() as f32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::clamp_angle;
+    use crate::unsafe_functions::vector2_ext::Vector2Ext;
+    use gdnative::prelude::{Angle, Vector2, Vector2Godot};
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_near(actual: Vector2, expected: Vector2) {
+        assert!(
+            (actual - expected).length() < 1e-6,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn positive_rotation_is_clockwise() {
+        // Y points down in 2D, so a positive rotation turns up towards right on screen.
+        let angle = Angle::radians(FRAC_PI_2);
+        assert_near(Vector2::up().rotated(angle), Vector2::right());
+        assert_near(Vector2::right().rotated(angle), Vector2::down());
+        assert_near(Vector2::down().rotated(angle), Vector2::left());
+        assert_near(Vector2::left().rotated(angle), Vector2::up());
+    }
+
+    #[test]
+    fn clamp_angle_limits_both_ways() {
+        let max = Angle::radians(0.5);
+        assert_eq!(clamp_angle(Angle::radians(0.25), max), Angle::radians(0.25));
+        assert_eq!(clamp_angle(Angle::radians(2.0), max), Angle::radians(0.5));
+        assert_eq!(clamp_angle(Angle::radians(-2.0), max), Angle::radians(-0.5));
+    }
+
+    #[test]
+    fn facing_right_is_zero_rotation() {
+        // `look_at` and `look_at_smooth` face the target with the right vector.
+        assert_eq!(Vector2::right().angle_from_x_axis(), Angle::radians(0.0));
+        assert_near(
+            Vector2::right().rotated(Vector2::down().angle_from_x_axis()),
+            Vector2::down(),
+        );
     }
 }
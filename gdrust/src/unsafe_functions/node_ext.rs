@@ -1,8 +1,20 @@
-use crate::unsafe_functions::option_ext::OptionExt;
-use gdnative::api::SceneTree;
-use gdnative::prelude::{Node, NodePath, Shared, SubClass};
+use crate::godot_panic;
+use crate::unsafe_functions::node_iter::{
+    glob_match, BreadthFirst, Children, DepthFirst, OfType, Siblings,
+};
+use crate::unsafe_functions::result_ext::ResultExt;
+use crate::unsafe_functions::scene_tree_ext::SceneTreeExt;
+use crate::unsafe_functions::timer_ext::SceneTreeTimerExt;
+use crate::unsafe_functions::tween::TweenBuilder;
+use gdnative::api::{Node2D, SceneTree, Spatial};
+use gdnative::nativescript::{Map, MapMut};
+use gdnative::prelude::{
+    NativeClass, Node, NodePath, RefInstance, Shared, SubClass, ToVariant, Unique, Variant,
+};
 use gdnative::NewRef;
-use gdnative::TRef;
+use gdnative::{GodotObject, Ref, TRef};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 pub trait NodeExt {
     /// Gets a typed node from a node path. This has an explicit `unsafe` block, and can panic. The
@@ -17,6 +29,23 @@ pub trait NodeExt {
     /// ```
     fn expect_node<T: SubClass<Node>, P: Into<NodePath>>(&self, path: P) -> TRef<T>;
 
+    /// Tries to get a typed node from a node path. Same as `expect_node`, but returns an error
+    /// instead of panicking. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `assume_safe` on the node at `path`.
+    /// # Errors
+    /// - `NodeLookupError::NotFound`: If no node is found at the path.
+    /// - `NodeLookupError::WrongType`: If a node is found at the path, but is not the correct type.
+    /// - `NodeLookupError::Freed`: If the node at the path has been freed.
+    ///
+    /// # GdScript equivalent
+    /// ```gdscript
+    /// get_node_or_null(path) as T
+    /// ```
+    fn try_node<T: SubClass<Node>, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> Result<TRef<T>, NodeLookupError>;
+
     /// Gets the parent node with a type. This has an explicit `unsafe` block, and can panic. The
     /// unsafe code is calling `assume_safe` on the parent node.
     /// # Panics
@@ -29,6 +58,20 @@ pub trait NodeExt {
     /// ```
     fn expect_parent<T: SubClass<Node>>(&self) -> TRef<T>;
 
+    /// Tries to get the parent node with a type. Same as `expect_parent`, but returns an error
+    /// instead of panicking. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `assume_safe` on the parent node.
+    /// # Errors
+    /// - `NodeLookupError::NotFound`: If no parent is found (root node). The path is `..`.
+    /// - `NodeLookupError::WrongType`: If the parent is not the correct type.
+    /// - `NodeLookupError::Freed`: If the parent has been freed.
+    ///
+    /// # GdScript equivalent
+    /// ```gdscript
+    /// get_parent() as T
+    /// ```
+    fn try_parent<T: SubClass<Node>>(&self) -> Result<TRef<T>, NodeLookupError>;
+
     /// Gets the scene tree. This has an explicit `unsafe` block, and can panic. The unsafe code is
     /// calling `assume_safe` on the scene tree.
     /// # Panics
@@ -39,6 +82,255 @@ pub trait NodeExt {
     /// get_tree()
     /// ```
     fn expect_tree(&self) -> TRef<SceneTree>;
+
+    /// Tries to get the scene tree. Same as `expect_tree`, but returns an error instead of
+    /// panicking. This has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on
+    /// the scene tree.
+    /// # Errors
+    /// - `NodeLookupError::NotFound`: If the node is not inside a scene tree. The path is empty.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_tree()
+    /// ```
+    fn try_tree(&self) -> Result<TRef<SceneTree>, NodeLookupError>;
+
+    /// Returns an iterator over the direct children of this node. This has an explicit `unsafe`
+    /// block. The unsafe code is calling `assume_safe` on each child.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_children()
+    /// ```
+    fn children(&self) -> Children<'_>;
+
+    /// Returns an iterator over the direct children of this node which are a `T`. Children of other
+    /// types are skipped. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `assume_safe` on each child.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for child in get_children():
+    ///     if child is T:
+    ///         yield(child)
+    /// ```
+    fn children_of_type<T: SubClass<Node>>(&self) -> OfType<'_, Children<'_>, T>;
+
+    /// Returns an iterator over the other children of this node's parent which are a `T`. Empty if
+    /// this node has no parent. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `assume_safe` on the parent and each sibling.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for sibling in get_parent().get_children():
+    ///     if sibling != self and sibling is T:
+    ///         yield(sibling)
+    /// ```
+    fn siblings_of_type<T: SubClass<Node>>(&self) -> OfType<'_, Siblings<'_>, T>;
+
+    /// Returns a depth-first (pre-order) iterator over every descendant of this node, not including
+    /// this node. This has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on
+    /// each descendant.
+    fn descendants(&self) -> DepthFirst<'_>;
+
+    /// Returns a breadth-first iterator over every descendant of this node, not including this
+    /// node. This has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on each
+    /// descendant.
+    fn descendants_breadth_first(&self) -> BreadthFirst<'_>;
+
+    /// Finds the first descendant whose name matches `pattern` and which is a `T`. `pattern` may
+    /// contain `*` and `?` like GdScript's `String.match`. Only direct children are searched if
+    /// `recursive` is `false`. Unlike `find_node`, nodes without an owner are included. This has an
+    /// explicit `unsafe` block. The unsafe code is calling `assume_safe` on each descendant.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// find_node(pattern, recursive, false) as T
+    /// ```
+    fn find_child<T: SubClass<Node>>(&self, pattern: &str, recursive: bool) -> Option<TRef<T>>;
+
+    /// Finds the closest ancestor which is a `T`, starting with the parent. This has an explicit
+    /// `unsafe` block. The unsafe code is calling `assume_safe` on each ancestor.
+    fn find_ancestor<T: SubClass<Node>>(&self) -> Option<TRef<T>>;
+
+    /// Gets the `T` script instance attached to the node at `path`. `path` may be relative, or
+    /// absolute (`/root/Main`) to get the instance on the scene root. This has an explicit `unsafe`
+    /// block, and can panic. The unsafe code is calling `assume_safe` on the node at `path`.
+    /// # Panics
+    /// - If no node is found at the path.
+    /// - If a node is found at the path, but is not `T`'s base type.
+    /// - If the node does not have the `T` script attached.
+    ///
+    /// # GdScript equivalent
+    /// ```gdscript
+    /// get_node(path) as T
+    /// ```
+    fn expect_node_instance<T: NativeClass, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> RefInstance<T, Shared>
+    where
+        T::Base: SubClass<Node>;
+
+    /// Tries to get the `T` script instance attached to the node at `path`. Same as
+    /// `expect_node_instance`, but returns an error instead of panicking. This has an explicit
+    /// `unsafe` block. The unsafe code is calling `assume_safe` on the node at `path`.
+    /// # Errors
+    /// - `NodeLookupError::NotFound`: If no node is found at the path.
+    /// - `NodeLookupError::WrongType`: If a node is found at the path, but is not `T`'s base type.
+    /// - `NodeLookupError::MissingScript`: If the node does not have the `T` script attached.
+    /// - `NodeLookupError::Freed`: If the node at the path has been freed.
+    fn try_node_instance<T: NativeClass, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> Result<RefInstance<T, Shared>, NodeLookupError>
+    where
+        T::Base: SubClass<Node>;
+
+    /// Gets the `T` script instance attached to the parent node. This has an explicit `unsafe`
+    /// block, and can panic. The unsafe code is calling `assume_safe` on the parent node.
+    /// # Panics
+    /// - If no parent is found (root node).
+    /// - If the parent is not `T`'s base type.
+    /// - If the parent does not have the `T` script attached.
+    ///
+    /// # GdScript equivalent
+    /// ```gdscript
+    /// get_parent() as T
+    /// ```
+    fn expect_parent_instance<T: NativeClass>(&self) -> RefInstance<T, Shared>
+    where
+        T::Base: SubClass<Node>;
+
+    /// Tries to get the `T` script instance attached to the parent node. Same as
+    /// `expect_parent_instance`, but returns an error instead of panicking. This has an explicit
+    /// `unsafe` block. The unsafe code is calling `assume_safe` on the parent node.
+    /// # Errors
+    /// - `NodeLookupError::NotFound`: If no parent is found (root node).
+    /// - `NodeLookupError::WrongType`: If the parent is not `T`'s base type.
+    /// - `NodeLookupError::MissingScript`: If the parent does not have the `T` script attached.
+    /// - `NodeLookupError::Freed`: If the parent has been freed.
+    fn try_parent_instance<T: NativeClass>(
+        &self,
+    ) -> Result<RefInstance<T, Shared>, NodeLookupError>
+    where
+        T::Base: SubClass<Node>;
+
+    /// Calls `f` with the `T` script attached to the node at `path` and its owner, and returns the
+    /// result. Same as `expect_node_instance(path).map(f)`. This has an explicit `unsafe` block,
+    /// and can panic. The unsafe code is calling `assume_safe` on the node at `path`.
+    /// # Panics
+    /// - Any of the reasons `expect_node_instance` panics.
+    /// - If the script can not be borrowed, for example if it is already mutably borrowed.
+    fn with_node_instance<T, P, F, R>(&self, path: P, f: F) -> R
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>,
+        T::UserData: Map,
+        P: Into<NodePath>,
+        F: FnOnce(&T, TRef<T::Base>) -> R;
+
+    /// Calls `f` with the mutable `T` script attached to the node at `path` and its owner, and
+    /// returns the result. Same as `expect_node_instance(path).map_mut(f)`. This has an explicit
+    /// `unsafe` block, and can panic. The unsafe code is calling `assume_safe` on the node at
+    /// `path`.
+    /// # Panics
+    /// - Any of the reasons `expect_node_instance` panics.
+    /// - If the script can not be borrowed mutably, for example if it is already borrowed.
+    fn with_node_instance_mut<T, P, F, R>(&self, path: P, f: F) -> R
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>,
+        T::UserData: MapMut,
+        P: Into<NodePath>,
+        F: FnOnce(&mut T, TRef<T::Base>) -> R;
+
+    /// Returns `true` if this node is in at least one of `groups`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// is_in_group(groups[0]) or is_in_group(groups[1]) or ...
+    /// ```
+    fn is_in_any_group(&self, groups: &[&str]) -> bool;
+
+    /// Adds `node` as a child at the end of the frame, so it is safe to call during physics
+    /// callbacks. `node` is handed over to the scene tree, and a shared reference to it is
+    /// returned. This has an explicit `unsafe` block. The unsafe code is calling `call_deferred`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// call_deferred("add_child", node)
+    /// ```
+    fn add_child_deferred<C: SubClass<Node>>(&self, node: Ref<C, Unique>) -> Ref<C, Shared>;
+
+    /// Removes `child` at the end of the frame, so it is safe to call during physics callbacks.
+    /// The removed node is not freed, you have to free it or add it to the tree again. This has an
+    /// explicit `unsafe` block. The unsafe code is calling `call_deferred`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// call_deferred("remove_child", child)
+    /// ```
+    fn remove_child_deferred<C: SubClass<Node>>(&self, child: TRef<C>);
+
+    /// Moves this node under `new_parent` at the end of the frame. If `keep_global_transform` is
+    /// `true` and this node is a `Spatial` or `Node2D`, its global transform is the same after
+    /// being moved. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `call_deferred`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var transform = global_transform
+    /// get_parent().call_deferred("remove_child", self)
+    /// new_parent.call_deferred("add_child", self)
+    /// call_deferred("set_global_transform", transform)
+    /// ```
+    fn reparent<P: SubClass<Node>>(&self, new_parent: TRef<P>, keep_global_transform: bool);
+
+    /// Queues all of the children of this node to be freed.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for child in get_children():
+    ///     child.queue_free()
+    /// ```
+    fn queue_free_children(&self);
+
+    /// Replaces this node with `node` at the end of the frame, and frees this node. `node` takes
+    /// this node's place in its parent and its groups. Like `replace_by`, this node's children are
+    /// moved to `node` too, after the children `node` already has. Free or move them first if
+    /// `node` is an instanced scene which should only keep its own children. `node` is handed over
+    /// to the scene tree, and a shared reference to it is returned. This has an explicit `unsafe`
+    /// block. The unsafe code is calling `call_deferred`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// call_deferred("replace_by", node, true)
+    /// queue_free()
+    /// ```
+    fn replace_with<C: SubClass<Node>>(&self, node: Ref<C, Unique>) -> Ref<C, Shared>;
+
+    /// Calls `method` on this node with `args` after `seconds`. A `SceneTreeTimer` is used, so no
+    /// `Timer` node needs to be added or freed. The timer keeps running while the game is paused.
+    /// # Panics
+    /// - If this node is not inside the scene tree.
+    /// - If this node does not have `method`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_tree().create_timer(seconds).connect("timeout", self, method, args)
+    /// ```
+    fn after(&self, seconds: f64, method: &str, args: &[Variant]);
+
+    /// Starts building a `Tween` which animates properties. The `Tween` is added as a child of this
+    /// node when it is started, and frees itself once it is done. See `TweenBuilder`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var tween = Tween.new()
+    /// add_child(tween)
+    /// ```
+    fn create_tween(&self) -> TweenBuilder;
 }
 
 impl<'a, T: SubClass<Node>> NodeExt for TRef<'a, T> {
@@ -46,36 +338,394 @@ impl<'a, T: SubClass<Node>> NodeExt for TRef<'a, T> {
         &self,
         path: P,
     ) -> TRef<'a, Child, Shared> {
+        self.try_node(path)
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_node<Child: SubClass<Node>, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> Result<TRef<'a, Child, Shared>, NodeLookupError> {
         let path = path.into();
+        let node = self.upcast::<Node>();
+        let child =
+            node.get_node_or_null(path.new_ref())
+                .ok_or_else(|| NodeLookupError::NotFound {
+                    path: path.to_string(),
+                    from: node_path(&node),
+                })?;
+        unsafe { cast_node(child) }
+    }
+
+    fn expect_parent<Child: SubClass<Node>>(&self) -> TRef<'a, Child, Shared> {
+        self.try_parent().unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_parent<Child: SubClass<Node>>(
+        &self,
+    ) -> Result<TRef<'a, Child, Shared>, NodeLookupError> {
+        let node = self.upcast::<Node>();
+        let parent = node.get_parent().ok_or_else(|| NodeLookupError::NotFound {
+            path: "..".to_string(),
+            from: node_path(&node),
+        })?;
+        unsafe { cast_node(parent) }
+    }
+
+    fn expect_tree(&self) -> TRef<'a, SceneTree, Shared> {
+        self.try_tree().unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_tree(&self) -> Result<TRef<'a, SceneTree, Shared>, NodeLookupError> {
+        let node = self.upcast::<Node>();
+        node.get_tree()
+            .map(|x| unsafe { x.assume_safe() })
+            .ok_or_else(|| NodeLookupError::NotFound {
+                path: String::new(),
+                from: node_path(&node),
+            })
+    }
+
+    fn children(&self) -> Children<'a> {
+        Children::new(Some(self.upcast()))
+    }
+
+    fn children_of_type<Child: SubClass<Node>>(&self) -> OfType<'a, Children<'a>, Child> {
+        OfType::new(self.children())
+    }
+
+    fn siblings_of_type<Sibling: SubClass<Node>>(&self) -> OfType<'a, Siblings<'a>, Sibling> {
+        OfType::new(Siblings::new(self.upcast()))
+    }
+
+    fn descendants(&self) -> DepthFirst<'a> {
+        DepthFirst::new(self.upcast())
+    }
+
+    fn descendants_breadth_first(&self) -> BreadthFirst<'a> {
+        BreadthFirst::new(self.upcast())
+    }
+
+    fn find_child<Child: SubClass<Node>>(
+        &self,
+        pattern: &str,
+        recursive: bool,
+    ) -> Option<TRef<'a, Child, Shared>> {
+        let matches = |node: TRef<'a, Node, Shared>| {
+            if glob_match(pattern, &node.name().to_string()) {
+                node.cast::<Child>()
+            } else {
+                None
+            }
+        };
+        if recursive {
+            self.descendants().find_map(matches)
+        } else {
+            self.children().find_map(matches)
+        }
+    }
+
+    fn find_ancestor<Ancestor: SubClass<Node>>(&self) -> Option<TRef<'a, Ancestor, Shared>> {
+        let mut current = self.upcast::<Node>().get_parent();
+        while let Some(parent) = current {
+            let parent = unsafe { cast_node::<Node>(parent) }.ok()?;
+            if let Some(ancestor) = parent.cast::<Ancestor>() {
+                return Some(ancestor);
+            }
+            current = parent.get_parent();
+        }
+        None
+    }
+
+    fn expect_node_instance<Script: NativeClass, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> RefInstance<'a, Script, Shared>
+    where
+        Script::Base: SubClass<Node>,
+    {
+        self.try_node_instance(path)
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_node_instance<Script: NativeClass, P: Into<NodePath>>(
+        &self,
+        path: P,
+    ) -> Result<RefInstance<'a, Script, Shared>, NodeLookupError>
+    where
+        Script::Base: SubClass<Node>,
+    {
+        self.try_node::<Script::Base, P>(path)
+            .and_then(instance_of::<Script>)
+    }
+
+    fn expect_parent_instance<Script: NativeClass>(&self) -> RefInstance<'a, Script, Shared>
+    where
+        Script::Base: SubClass<Node>,
+    {
+        self.try_parent_instance()
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_parent_instance<Script: NativeClass>(
+        &self,
+    ) -> Result<RefInstance<'a, Script, Shared>, NodeLookupError>
+    where
+        Script::Base: SubClass<Node>,
+    {
+        self.try_parent::<Script::Base>()
+            .and_then(instance_of::<Script>)
+    }
+
+    fn with_node_instance<Script, P, F, R>(&self, path: P, f: F) -> R
+    where
+        Script: NativeClass,
+        Script::Base: SubClass<Node>,
+        Script::UserData: Map,
+        P: Into<NodePath>,
+        F: FnOnce(&Script, TRef<Script::Base>) -> R,
+    {
+        self.expect_node_instance::<Script, P>(path)
+            .map(f)
+            .godot_expect("Could not borrow the script")
+    }
+
+    fn with_node_instance_mut<Script, P, F, R>(&self, path: P, f: F) -> R
+    where
+        Script: NativeClass,
+        Script::Base: SubClass<Node>,
+        Script::UserData: MapMut,
+        P: Into<NodePath>,
+        F: FnOnce(&mut Script, TRef<Script::Base>) -> R,
+    {
+        self.expect_node_instance::<Script, P>(path)
+            .map_mut(f)
+            .godot_expect("Could not mutably borrow the script")
+    }
+
+    fn is_in_any_group(&self, groups: &[&str]) -> bool {
+        let node = self.upcast::<Node>();
+        groups.iter().any(|x| node.is_in_group(*x))
+    }
+
+    fn add_child_deferred<C: SubClass<Node>>(&self, node: Ref<C, Unique>) -> Ref<C, Shared> {
+        let node = node.into_shared();
         unsafe {
-            self.upcast()
-                .get_node(path.new_ref())
-                .godot_expect(
-                    format!("Could not find a node at {}", path.new_ref().to_string()).as_str(),
-                )
-                .assume_safe()
-                .cast::<Child>()
-                .godot_expect("Could not cast")
+            self.upcast::<Node>()
+                .call_deferred("add_child", &[node.to_variant(), false.to_variant()]);
         }
+        node
     }
 
-    fn expect_parent<Child: SubClass<Node>>(&self) -> TRef<'a, Child, Shared> {
+    fn remove_child_deferred<C: SubClass<Node>>(&self, child: TRef<C>) {
         unsafe {
-            self.upcast()
-                .get_parent()
-                .godot_expect("Could not get a parent node")
-                .assume_safe()
-                .cast::<Child>()
-                .godot_expect("Could not cast")
+            self.upcast::<Node>()
+                .call_deferred("remove_child", &[child.upcast::<Node>().to_variant()]);
         }
     }
 
-    fn expect_tree(&self) -> TRef<'a, SceneTree, Shared> {
+    fn reparent<P: SubClass<Node>>(&self, new_parent: TRef<P>, keep_global_transform: bool) {
+        let node = self.upcast::<Node>();
+        let transform = if !keep_global_transform {
+            None
+        } else if let Some(spatial) = node.cast::<Spatial>() {
+            Some(spatial.global_transform().to_variant())
+        } else if let Some(node2d) = node.cast::<Node2D>() {
+            Some(node2d.get_global_transform().to_variant())
+        } else {
+            None
+        };
+        // Deferred calls run in the order they are queued, so the node is removed, added and then
+        // moved back to its global transform.
         unsafe {
-            self.upcast()
-                .get_tree()
-                .godot_expect("Expected scene tree, but couldn't find it")
-                .assume_safe()
+            if let Some(parent) = node.get_parent() {
+                parent
+                    .assume_safe()
+                    .call_deferred("remove_child", &[node.to_variant()]);
+            }
+            new_parent
+                .upcast::<Node>()
+                .call_deferred("add_child", &[node.to_variant(), false.to_variant()]);
+            if let Some(transform) = transform {
+                node.call_deferred("set_global_transform", &[transform]);
+            }
+        }
+    }
+
+    fn queue_free_children(&self) {
+        for child in self.children() {
+            child.queue_free();
+        }
+    }
+
+    fn replace_with<C: SubClass<Node>>(&self, node: Ref<C, Unique>) -> Ref<C, Shared> {
+        let node = node.into_shared();
+        let this = self.upcast::<Node>();
+        unsafe {
+            this.call_deferred("replace_by", &[node.to_variant(), true.to_variant()]);
+        }
+        this.queue_free();
+        node
+    }
+
+    fn after(&self, seconds: f64, method: &str, args: &[Variant]) {
+        self.expect_tree()
+            .create_one_shot_timer(seconds)
+            .on_timeout(self.upcast::<Node>(), method, args);
+    }
+
+    fn create_tween(&self) -> TweenBuilder<'a> {
+        TweenBuilder::new(self.upcast())
+    }
+}
+
+/// Gets the `T` script instance attached to `node`.
+pub(crate) fn instance_of<T: NativeClass>(
+    node: TRef<T::Base, Shared>,
+) -> Result<RefInstance<T, Shared>, NodeLookupError>
+where
+    T::Base: SubClass<Node>,
+{
+    node.cast_instance::<T>()
+        .ok_or_else(|| NodeLookupError::MissingScript {
+            path: node_path(&node.upcast::<Node>()),
+            expected: T::class_name(),
+        })
+}
+
+/// Checks that `node` has not been freed, calls `assume_safe`, and casts it to `T`.
+///
+/// # Safety
+/// The same as `Ref::assume_safe`.
+pub(crate) unsafe fn cast_node<'a, T: SubClass<Node>>(
+    node: Ref<Node, Shared>,
+) -> Result<TRef<'a, T, Shared>, NodeLookupError> {
+    if !node.is_instance_sane() {
+        return Err(NodeLookupError::Freed);
+    }
+    cast_checked(node.assume_safe())
+}
+
+/// Casts `node` to `T`, and returns a `NodeLookupError::WrongType` if it is not a `T`.
+pub(crate) fn cast_checked<T: SubClass<Node>>(
+    node: TRef<Node, Shared>,
+) -> Result<TRef<T, Shared>, NodeLookupError> {
+    node.cast::<T>().ok_or_else(|| NodeLookupError::WrongType {
+        path: node_path(&node),
+        expected: T::class_name(),
+        actual: node.get_class().to_string(),
+    })
+}
+
+/// The absolute path of `node`, or its name if it is not inside the scene tree.
+pub(crate) fn node_path(node: &Node) -> String {
+    if node.is_inside_tree() {
+        node.get_path().to_string()
+    } else {
+        format!("{} (outside the scene tree)", node.name())
+    }
+}
+
+/// An error when using one of the `try_` functions in `NodeExt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeLookupError {
+    /// There was no node at `path`, relative to the node at `from`.
+    NotFound { path: String, from: String },
+    /// The node at `path` was an `actual`, which can not be cast to `expected`.
+    WrongType {
+        path: String,
+        expected: &'static str,
+        actual: String,
+    },
+    /// The node at `path` does not have the `expected` script attached.
+    MissingScript {
+        path: String,
+        expected: &'static str,
+    },
+    /// The node was found, but it has already been freed.
+    Freed,
+}
+
+impl Display for NodeLookupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeLookupError::NotFound { path, from } if path.is_empty() => {
+                write!(f, "{} is not inside a scene tree", from)
+            }
+            NodeLookupError::NotFound { path, from } => {
+                write!(f, "Could not find a node at {} from {}", path, from)
+            }
+            NodeLookupError::WrongType {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Expected the node at {} to be a {}, but it is a {}",
+                path, expected, actual
+            ),
+            NodeLookupError::MissingScript { path, expected } => write!(
+                f,
+                "Expected the node at {} to have {} attached, but it did not",
+                path, expected
+            ),
+            NodeLookupError::Freed => write!(f, "The node has already been freed"),
         }
     }
 }
+
+impl Error for NodeLookupError {}
+
+#[cfg(test)]
+mod test {
+    use super::NodeLookupError;
+
+    #[test]
+    fn not_found_display() {
+        let error = NodeLookupError::NotFound {
+            path: "Player".to_string(),
+            from: "/root/Main".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Could not find a node at Player from /root/Main"
+        );
+    }
+
+    #[test]
+    fn tree_not_found_display() {
+        let error = NodeLookupError::NotFound {
+            path: String::new(),
+            from: "Player (outside the scene tree)".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Player (outside the scene tree) is not inside a scene tree"
+        );
+    }
+
+    #[test]
+    fn wrong_type_display() {
+        let error = NodeLookupError::WrongType {
+            path: "/root/Main/Player".to_string(),
+            expected: "KinematicBody",
+            actual: "Node2D".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the node at /root/Main/Player to be a KinematicBody, but it is a Node2D"
+        );
+    }
+
+    #[test]
+    fn missing_script_display() {
+        let error = NodeLookupError::MissingScript {
+            path: "/root/Main/Player".to_string(),
+            expected: "Player",
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the node at /root/Main/Player to have Player attached, but it did not"
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::unsafe_functions::node_ext::{cast_checked, cast_node, NodeLookupError};
+use gdnative::prelude::{NativeClass, Node, RefInstance, Shared, SubClass, VariantArray};
+use gdnative::TRef;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// An iterator over the direct children of a node. See `NodeExt::children`.
+pub struct Children<'a> {
+    parent: Option<TRef<'a, Node, Shared>>,
+    index: i64,
+    count: i64,
+}
+
+impl<'a> Children<'a> {
+    pub(crate) fn new(parent: Option<TRef<'a, Node, Shared>>) -> Self {
+        Self {
+            parent,
+            index: 0,
+            count: parent.map_or(0, |x| x.get_child_count()),
+        }
+    }
+}
+
+impl<'a> Iterator for Children<'a> {
+    type Item = TRef<'a, Node, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = self.parent?;
+        while self.index < self.count {
+            let child = parent.get_child(self.index);
+            self.index += 1;
+            if let Some(child) = child.and_then(|x| unsafe { cast_node(x) }.ok()) {
+                return Some(child);
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the other children of a node's parent. See `NodeExt::siblings_of_type`.
+pub struct Siblings<'a> {
+    children: Children<'a>,
+    instance_id: i64,
+}
+
+impl<'a> Siblings<'a> {
+    pub(crate) fn new(node: TRef<'a, Node, Shared>) -> Self {
+        let parent = node.get_parent().and_then(|x| unsafe { cast_node(x) }.ok());
+        Self {
+            children: Children::new(parent),
+            instance_id: node.get_instance_id(),
+        }
+    }
+}
+
+impl<'a> Iterator for Siblings<'a> {
+    type Item = TRef<'a, Node, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instance_id = self.instance_id;
+        self.children.find(|x| x.get_instance_id() != instance_id)
+    }
+}
+
+/// A pre-order, depth-first iterator over the descendants of a node. This is the same order
+/// Godot's `find_node` searches in. See `NodeExt::descendants`.
+pub struct DepthFirst<'a> {
+    stack: Vec<TRef<'a, Node, Shared>>,
+}
+
+impl<'a> DepthFirst<'a> {
+    pub(crate) fn new(root: TRef<'a, Node, Shared>) -> Self {
+        let mut stack: Vec<TRef<'a, Node, Shared>> = Children::new(Some(root)).collect();
+        stack.reverse();
+        Self { stack }
+    }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = TRef<'a, Node, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let len = self.stack.len();
+        self.stack.extend(Children::new(Some(node)));
+        self.stack[len..].reverse();
+        Some(node)
+    }
+}
+
+/// A breadth-first iterator over the descendants of a node. See
+/// `NodeExt::descendants_breadth_first`.
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<TRef<'a, Node, Shared>>,
+}
+
+impl<'a> BreadthFirst<'a> {
+    pub(crate) fn new(root: TRef<'a, Node, Shared>) -> Self {
+        Self {
+            queue: Children::new(Some(root)).collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = TRef<'a, Node, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(Children::new(Some(node)));
+        Some(node)
+    }
+}
+
+/// An iterator over the nodes in an array, such as the nodes in a group. See
+/// `SceneTreeExt::nodes_in_group` and `AreaExt::overlapping_bodies_of_type`.
+pub struct GroupNodes<'a> {
+    nodes: VariantArray,
+    index: i32,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> GroupNodes<'a> {
+    pub(crate) fn new(nodes: VariantArray) -> Self {
+        Self {
+            nodes,
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for GroupNodes<'a> {
+    type Item = TRef<'a, Node, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.nodes.len() {
+            let node = self.nodes.get(self.index).try_to_object::<Node>();
+            self.index += 1;
+            if let Some(node) = node.and_then(|x| unsafe { cast_node(x) }.ok()) {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// Wraps one of the node iterators, and only yields the nodes which can be cast to `T`.
+pub struct OfType<'a, I, T> {
+    iter: I,
+    _marker: PhantomData<(&'a (), fn() -> T)>,
+}
+
+impl<'a, I, T> OfType<'a, I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = TRef<'a, Node, Shared>>, T: SubClass<Node>> Iterator
+    for OfType<'a, I, T>
+{
+    type Item = TRef<'a, T, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|x| x.cast::<T>())
+    }
+}
+
+/// Wraps one of the node iterators, and yields a `NodeLookupError::WrongType` for each node which
+/// can not be cast to `T`.
+pub struct TryOfType<'a, I, T> {
+    iter: I,
+    _marker: PhantomData<(&'a (), fn() -> T)>,
+}
+
+impl<'a, I, T> TryOfType<'a, I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = TRef<'a, Node, Shared>>, T: SubClass<Node>> Iterator
+    for TryOfType<'a, I, T>
+{
+    type Item = Result<TRef<'a, T, Shared>, NodeLookupError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(cast_checked)
+    }
+}
+
+/// Wraps one of the node iterators, and only yields the nodes which have the `T` script attached.
+pub struct Instances<'a, I, T> {
+    iter: I,
+    _marker: PhantomData<(&'a (), fn() -> T)>,
+}
+
+impl<'a, I, T> Instances<'a, I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, T> Iterator for Instances<'a, I, T>
+where
+    I: Iterator<Item = TRef<'a, Node, Shared>>,
+    T: NativeClass,
+    T::Base: SubClass<Node>,
+{
+    type Item = RefInstance<'a, T, Shared>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find_map(|x| x.cast::<T::Base>().and_then(|x| x.cast_instance::<T>()))
+    }
+}
+
+/// Matches a node name against a pattern the same way GdScript's `String.match` does. `*` matches
+/// zero or more characters, and `?` matches exactly one character.
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let mut p = 0;
+    let mut n = 0;
+    // The position of the last `*` and the position in `name` it is currently matched up to.
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|x| *x == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::glob_match;
+
+    #[test]
+    fn glob_match_exact() {
+        assert!(glob_match("Player", "Player"));
+        assert!(!glob_match("Player", "Player2"));
+        assert!(!glob_match("Player", "player"));
+    }
+
+    #[test]
+    fn glob_match_star() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "Player"));
+        assert!(glob_match("Enemy*", "Enemy"));
+        assert!(glob_match("Enemy*", "Enemy12"));
+        assert!(glob_match("*Spawn*Point", "EnemySpawnerPoint"));
+        assert!(!glob_match("*Spawn*Point", "EnemySpawnerPoints"));
+        assert!(glob_match("*a", "*xa"));
+        assert!(glob_match("*", "*"));
+    }
+
+    #[test]
+    fn glob_match_question_mark() {
+        assert!(glob_match("Enemy?", "Enemy1"));
+        assert!(!glob_match("Enemy?", "Enemy"));
+        assert!(!glob_match("Enemy?", "Enemy12"));
+        assert!(glob_match("?*", "A"));
+    }
+}
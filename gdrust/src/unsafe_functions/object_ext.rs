@@ -1,17 +1,65 @@
 use crate::godot_panic;
+use gdnative::api::{NativeScript, Resource};
 use gdnative::prelude::{NativeClass, Object, RefInstance, SubClass};
 use gdnative::thread_access::ThreadAccess;
 use gdnative::{GodotObject, TRef};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 /// An error when using `try_as_instance`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TryAsError {
     /// We were unable to get as an instance because the node was not of the correct type.
-    Cast,
+    Cast {
+        /// The class of the object.
+        actual: String,
+        /// The base class of the script.
+        expected: &'static str,
+    },
     /// We were unable to get as an instance because the expected script was not attached to the
     /// Node.
-    Instance,
+    Instance {
+        /// The class of the object.
+        class: String,
+        /// The class name of the script.
+        expected: &'static str,
+        /// The script which is attached instead, if there is one. See `attached_script`.
+        attached: Option<String>,
+    },
 }
 
+impl Display for TryAsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryAsError::Cast { actual, expected } => write!(
+                f,
+                "Expected to cast to {}, but the object is a {}",
+                expected, actual
+            ),
+            TryAsError::Instance {
+                class,
+                expected,
+                attached: Some(attached),
+            } => write!(
+                f,
+                "Expected the {} to have {} attached, but it has {} attached",
+                class, expected, attached
+            ),
+            TryAsError::Instance {
+                class,
+                expected,
+                attached: None,
+            } => write!(
+                f,
+                "Expected the {} to have {} attached, but it has no script",
+                class, expected
+            ),
+        }
+    }
+}
+
+impl Error for TryAsError {}
+
 pub trait ObjectExt<'a, A: ThreadAccess, Class: SubClass<Object>> {
     /// Tries to cast a given node as `T`. Returns `Ok` with the `RefInstance` if found. Returns `Err`
     /// if it was unable to get the `RefInstance`:
@@ -36,25 +84,77 @@ impl<'a, A: ThreadAccess, Class: SubClass<Object>> ObjectExt<'a, A, Class> for T
     where
         <T as NativeClass>::Base: SubClass<Class>,
     {
+        let object = self.upcast::<Object>();
         self.cast::<<T as NativeClass>::Base>()
-            .ok_or(TryAsError::Cast)
-            .and_then(|x| x.cast_instance().ok_or(TryAsError::Instance))
+            .ok_or_else(|| TryAsError::Cast {
+                actual: object.get_class().to_string(),
+                expected: <T as NativeClass>::Base::class_name(),
+            })
+            .and_then(|x| {
+                x.cast_instance().ok_or_else(|| TryAsError::Instance {
+                    class: object.get_class().to_string(),
+                    expected: <T as NativeClass>::class_name(),
+                    attached: attached_script(&object),
+                })
+            })
     }
 
     fn expect_as_instance<T: NativeClass>(self) -> RefInstance<'a, T, A>
     where
         <T as NativeClass>::Base: SubClass<Class>,
     {
-        match self.try_as_instance() {
-            Ok(x) => x,
-            Err(TryAsError::Cast) => godot_panic!(
-                "Expected to cast to {}, but that was not found",
-                <T as NativeClass>::Base::class_name()
-            ),
-            Err(TryAsError::Instance) => godot_panic!(
-                "Expected Node to have {} attached, but it did not",
-                <T as NativeClass>::class_name()
-            ),
-        }
+        self.try_as_instance()
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+}
+
+/// Describes the script attached to `object`. This is the class name for a `NativeScript`, and the
+/// path for any other script. This has an explicit `unsafe` block. The unsafe code is calling
+/// `assume_safe` on the script.
+pub(crate) fn attached_script(object: &Object) -> Option<String> {
+    let script = object.get_script()?;
+    let script = unsafe { script.assume_safe() };
+    match script.cast::<NativeScript>() {
+        Some(script) => Some(script.class_name().to_string()),
+        None => script.cast::<Resource>().map(|x| x.path().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TryAsError;
+
+    #[test]
+    fn try_as_error_cast() {
+        let error = TryAsError::Cast {
+            actual: "Node2D".to_string(),
+            expected: "KinematicBody",
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected to cast to KinematicBody, but the object is a Node2D"
+        );
+    }
+
+    #[test]
+    fn try_as_error_instance() {
+        let error = TryAsError::Instance {
+            class: "KinematicBody".to_string(),
+            expected: "Player",
+            attached: Some("res://enemy.gd".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the KinematicBody to have Player attached, but it has res://enemy.gd attached"
+        );
+        let error = TryAsError::Instance {
+            class: "KinematicBody".to_string(),
+            expected: "Player",
+            attached: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the KinematicBody to have Player attached, but it has no script"
+        );
     }
 }
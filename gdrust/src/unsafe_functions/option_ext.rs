@@ -1,22 +1,48 @@
-use crate::godot_panic;
+use crate::unsafe_functions::{node_context, panic_at_caller};
+use gdnative::prelude::{Node, SubClass};
+use gdnative::TRef;
 
 pub trait OptionExt<T> {
     /// Performs the exact same functionality as `Option::unwrap`, but prints errors to godot's
-    /// output also.
+    /// output also. The error points to the line which called this.
     fn godot_unwrap(self) -> T;
 
     /// Performs the exact same functionality as `Option::expect`, but prints errors to godot's
-    /// output also.
+    /// output also. The error points to the line which called this.
     fn godot_expect(self, msg: &str) -> T;
+
+    /// Same as `godot_expect`, but the error also says which node it happened in, with its path,
+    /// class and script. Pass the `owner` of the script calling this.
+    fn godot_expect_in<N: SubClass<Node>>(self, node: TRef<N>, msg: &str) -> T;
 }
 
 #[allow(clippy::single_match_else)] // To match Option's syntax
 impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
     fn godot_unwrap(self) -> T {
-        self.unwrap_or_else(|| godot_panic!("called `Option::unwrap()` on a `None` value"))
+        match self {
+            Some(x) => x,
+            None => panic_at_caller("called `Option::unwrap()` on a `None` value"),
+        }
     }
 
+    #[track_caller]
     fn godot_expect(self, msg: &str) -> T {
-        self.unwrap_or_else(|| godot_panic!("{}", msg))
+        match self {
+            Some(x) => x,
+            None => panic_at_caller(msg),
+        }
+    }
+
+    #[track_caller]
+    fn godot_expect_in<N: SubClass<Node>>(self, node: TRef<N>, msg: &str) -> T {
+        match self {
+            Some(x) => x,
+            None => panic_at_caller(&format!(
+                "{} in {}",
+                msg,
+                node_context(&node.upcast::<Node>())
+            )),
+        }
     }
 }
@@ -1,38 +1,311 @@
-use crate::unsafe_functions::result_ext::ResultExt;
-use gdnative::prelude::{Node, PackedScene, SubClass};
-use gdnative::TRef;
+use crate::godot_panic;
+use crate::unsafe_functions::object_ext::attached_script;
+use gdnative::prelude::{Instance, NativeClass, Node, PackedScene, SubClass, Unique};
+use gdnative::ref_kind::ManuallyManaged;
+use gdnative::{GodotObject, Ref, TRef};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
 
 pub trait PackedSceneExt<'a> {
-    /// Tries to instance a scene (with `edit_state=0)` and cast it. Returns errors if it fails.
+    /// Tries to instance a scene (with `EditState::Disabled`) and cast it. Returns errors if it
+    /// fails.
     ///
     /// # Errors
-    /// `FailedToInstance`: If `ResourceLoader.instance(0)` returns `None`.
+    /// `FailedToInstance`: If `PackedScene.instance(GEN_EDIT_STATE_DISABLED)` returns `None`.
     /// `IncorrectType`: If the cast to `T` fails.
     fn try_instance_as<T: SubClass<Node>>(&'a self) -> Result<TRef<'a, T>, InstanceSceneErr>;
 
     /// Expects a scene to be instanced correctly. Same as `try_instance_as` with an expect.
     fn expect_instance_as<T: SubClass<Node>>(&'a self) -> TRef<'a, T>;
+
+    /// Instances a scene, casts it to `T`, calls `configure` on it and adds it as a child of
+    /// `parent`. `configure` is called before the node enters the tree, so `_ready` sees its
+    /// changes. If `set_owner` is `true`, the owner of the node is set to the owner of `parent`
+    /// (or `parent` if it has no owner), so the node is saved along with the scene. The instance is
+    /// freed if it can not be cast, or if `configure` panics. This has an explicit `unsafe` block.
+    /// The unsafe code is calling `assume_unique` on the new instance, and `assume_safe` on it
+    /// while `configure` runs. Nothing else has a reference to the instance until it is added to
+    /// `parent`.
+    ///
+    /// # Errors
+    /// `FailedToInstance`: If `PackedScene.instance(edit_state)` returns `None`.
+    /// `IncorrectType`: If the cast to `T` fails.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var node = scene.instance(edit_state) as T
+    /// configure(node)
+    /// parent.add_child(node)
+    /// node.owner = parent.owner if parent.owner else parent
+    /// ```
+    fn instance_into<'p, T, P, F>(
+        &'a self,
+        parent: TRef<'p, P>,
+        edit_state: EditState,
+        set_owner: bool,
+        configure: F,
+    ) -> Result<TRef<'p, T>, InstanceSceneErr>
+    where
+        T: SubClass<Node> + GodotObject<RefKind = ManuallyManaged>,
+        P: SubClass<Node>,
+        F: FnOnce(TRef<T>);
+
+    /// Instances a scene and returns the root node together with its `T` script. The instance is
+    /// not in the scene tree yet, so it is returned as a `Unique` `Instance`, which can be added to
+    /// the tree later. The instance is freed if it does not have the `T` script attached. This has
+    /// an explicit `unsafe` block. The unsafe code is calling `assume_unique` on the new instance.
+    ///
+    /// # Errors
+    /// `FailedToInstance`: If `PackedScene.instance(edit_state)` returns `None`.
+    /// `IncorrectType`: If the cast to `T::Base` fails.
+    /// `MissingScript`: If the instance does not have the `T` script attached.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var node = scene.instance(edit_state) as T
+    /// ```
+    fn instance_with_script<T>(
+        &'a self,
+        edit_state: EditState,
+    ) -> Result<Instance<T, Unique>, InstanceSceneErr>
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node> + GodotObject<RefKind = ManuallyManaged>;
 }
 
 impl<'a> PackedSceneExt<'a> for TRef<'a, PackedScene> {
     fn try_instance_as<T: SubClass<Node>>(&'a self) -> Result<TRef<'a, T>, InstanceSceneErr> {
-        self.instance(0)
-            .ok_or(InstanceSceneErr::FailedToInstance)
+        self.instance(EditState::Disabled.value())
+            .ok_or_else(|| InstanceSceneErr::FailedToInstance {
+                path: self.path().to_string(),
+            })
             .and_then(|x| unsafe {
-                x.assume_safe()
-                    .cast::<T>()
-                    .ok_or(InstanceSceneErr::IncorrectType)
+                let x = x.assume_safe();
+                x.cast::<T>()
+                    .ok_or_else(|| InstanceSceneErr::IncorrectType {
+                        path: self.path().to_string(),
+                        expected: T::class_name(),
+                        actual: x.get_class().to_string(),
+                    })
             })
     }
 
     fn expect_instance_as<T: SubClass<Node>>(&'a self) -> TRef<'a, T> {
         self.try_instance_as::<T>()
-            .godot_expect("Failed to instance scene")
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn instance_into<'p, T, P, F>(
+        &'a self,
+        parent: TRef<'p, P>,
+        edit_state: EditState,
+        set_owner: bool,
+        configure: F,
+    ) -> Result<TRef<'p, T>, InstanceSceneErr>
+    where
+        T: SubClass<Node> + GodotObject<RefKind = ManuallyManaged>,
+        P: SubClass<Node>,
+        F: FnOnce(TRef<T>),
+    {
+        let node = instance_unique(self, edit_state)?
+            .try_cast::<T>()
+            .map_err(|x| incorrect_type::<T>(self, x))?;
+        let parent = parent.upcast::<Node>();
+        // Nothing owns the node until it is added to `parent`, so it is freed here if `configure`
+        // unwinds.
+        let node = unsafe { node.into_shared().assume_safe() };
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| configure(node))) {
+            unsafe { node.claim().assume_unique() }.free();
+            resume_unwind(payload);
+        }
+        // The node is owned by `parent` from here on, so it lives as long as `parent` does.
+        parent.add_child(node, false);
+        if set_owner {
+            let owner = parent.owner().unwrap_or_else(|| parent.claim());
+            node.upcast::<Node>().set_owner(owner);
+        }
+        Ok(node)
+    }
+
+    fn instance_with_script<T>(
+        &'a self,
+        edit_state: EditState,
+    ) -> Result<Instance<T, Unique>, InstanceSceneErr>
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node> + GodotObject<RefKind = ManuallyManaged>,
+    {
+        instance_unique(self, edit_state)?
+            .try_cast::<T::Base>()
+            .map_err(|x| incorrect_type::<T::Base>(self, x))?
+            .try_cast_instance::<T>()
+            .map_err(|x| {
+                let error = InstanceSceneErr::MissingScript {
+                    path: self.path().to_string(),
+                    expected: T::class_name(),
+                    attached: attached_script(&x.as_ref().upcast::<Node>()),
+                };
+                x.upcast::<Node>().free();
+                error
+            })
     }
 }
 
-#[derive(Debug)]
+/// Frees an instance which could not be cast to `T`, and describes why.
+fn incorrect_type<T: GodotObject>(
+    scene: &PackedScene,
+    node: Ref<Node, Unique>,
+) -> InstanceSceneErr {
+    let error = InstanceSceneErr::IncorrectType {
+        path: scene.path().to_string(),
+        expected: T::class_name(),
+        actual: node.as_ref().get_class().to_string(),
+    };
+    node.free();
+    error
+}
+
+/// Instances `scene`. Nothing else has a reference to a new instance, so it is unique.
+fn instance_unique(
+    scene: &PackedScene,
+    edit_state: EditState,
+) -> Result<Ref<Node, Unique>, InstanceSceneErr> {
+    scene
+        .instance(edit_state.value())
+        .map(|x| unsafe { x.assume_unique() })
+        .ok_or_else(|| InstanceSceneErr::FailedToInstance {
+            path: scene.path().to_string(),
+        })
+}
+
+/// The edit state to instance a scene with. Anything other than `Disabled` is only useful in
+/// editor plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditState {
+    /// The scene is instanced without any edit state. This is what `try_instance_as` uses.
+    Disabled,
+    /// The scene is instanced with the local edit state, like a scene instanced in the editor.
+    Instance,
+    /// The scene is instanced with the edit state of a main scene, like the scene being edited.
+    Main,
+}
+
+#[allow(clippy::derivable_impls)] // #[default] on a variant needs Rust 1.62
+impl Default for EditState {
+    fn default() -> Self {
+        EditState::Disabled
+    }
+}
+
+impl EditState {
+    /// The value Godot uses for this edit state.
+    pub fn value(self) -> i64 {
+        match self {
+            EditState::Disabled => PackedScene::GEN_EDIT_STATE_DISABLED,
+            EditState::Instance => PackedScene::GEN_EDIT_STATE_INSTANCE,
+            EditState::Main => PackedScene::GEN_EDIT_STATE_MAIN,
+        }
+    }
+}
+
+/// An error when instancing a scene with `PackedSceneExt`. `path` is the path of the scene.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InstanceSceneErr {
-    FailedToInstance,
-    IncorrectType,
+    /// Godot could not instance the scene.
+    FailedToInstance { path: String },
+    /// The root node of the scene is not an `expected`.
+    IncorrectType {
+        path: String,
+        expected: &'static str,
+        actual: String,
+    },
+    /// The root node of the scene does not have the `expected` script attached. `attached` is the
+    /// script which is attached instead, if there is one.
+    MissingScript {
+        path: String,
+        expected: &'static str,
+        attached: Option<String>,
+    },
+}
+
+impl Display for InstanceSceneErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstanceSceneErr::FailedToInstance { path } => {
+                write!(f, "Could not instance the scene {}", path)
+            }
+            InstanceSceneErr::IncorrectType {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Expected the root of {} to be a {}, but it was a {}",
+                path, expected, actual
+            ),
+            InstanceSceneErr::MissingScript {
+                path,
+                expected,
+                attached: Some(attached),
+            } => write!(
+                f,
+                "Expected the root of {} to have {} attached, but it has {} attached",
+                path, expected, attached
+            ),
+            InstanceSceneErr::MissingScript {
+                path,
+                expected,
+                attached: None,
+            } => write!(
+                f,
+                "Expected the root of {} to have {} attached, but it has no script",
+                path, expected
+            ),
+        }
+    }
+}
+
+impl Error for InstanceSceneErr {}
+
+#[cfg(test)]
+mod test {
+    use super::InstanceSceneErr;
+
+    #[test]
+    fn instance_scene_err_failed_to_instance() {
+        let error = InstanceSceneErr::FailedToInstance {
+            path: "res://enemy.tscn".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Could not instance the scene res://enemy.tscn"
+        );
+    }
+
+    #[test]
+    fn instance_scene_err_incorrect_type() {
+        let error = InstanceSceneErr::IncorrectType {
+            path: "res://enemy.tscn".to_string(),
+            expected: "KinematicBody",
+            actual: "Spatial".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the root of res://enemy.tscn to be a KinematicBody, but it was a Spatial"
+        );
+    }
+
+    #[test]
+    fn instance_scene_err_missing_script() {
+        let error = InstanceSceneErr::MissingScript {
+            path: "res://enemy.tscn".to_string(),
+            expected: "Enemy",
+            attached: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the root of res://enemy.tscn to have Enemy attached, but it has no script"
+        );
+    }
 }
@@ -0,0 +1,210 @@
+use crate::unsafe_functions::node_ext::cast_node;
+use crate::unsafe_functions::node_iter::{GroupNodes, OfType};
+use gdnative::api::{Area, Area2D, Node2D, RigidBody, RigidBody2D, Spatial};
+use gdnative::prelude::{
+    Dictionary, Node, Rid, Shared, SubClass, Unique, VariantArray, Vector2, Vector3,
+};
+use gdnative::TRef;
+
+/// The collision mask which collides with every layer.
+pub const ALL_LAYERS: u32 = 0x7FFF_FFFF;
+
+pub trait PhysicsExt {
+    /// Casts a ray from `from` to `to` in global space, and returns the first body it hits. The
+    /// bodies in `exclude` are ignored, which is usually `get_rid()` of the node casting the ray.
+    /// Only layers in `mask` are hit. Use `ALL_LAYERS` to hit everything. Returns `None` if nothing
+    /// is hit, or if the body hit is not a `T`. Use `CollisionObject` as `T` to get any body.
+    ///
+    /// This can only be called from `_physics_process`, since the space is locked otherwise. This
+    /// has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on the world, the
+    /// space state and the body hit.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var hit = get_world().direct_space_state.intersect_ray(from, to, exclude, mask)
+    /// if hit and hit.collider is T:
+    ///     ...
+    /// ```
+    fn raycast<T: SubClass<Node>>(
+        &self,
+        from: Vector3,
+        to: Vector3,
+        exclude: &[Rid],
+        mask: u32,
+    ) -> Option<RayHit<T, Vector3>>;
+}
+
+pub trait Physics2DExt {
+    /// Casts a ray from `from` to `to` in global space, and returns the first body it hits. Same as
+    /// `PhysicsExt::raycast`, but for 2D. Use `CollisionObject2D` as `T` to get any body. This has
+    /// an explicit `unsafe` block. The unsafe code is calling `assume_safe` on the world, the space
+    /// state and the body hit.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var hit = get_world_2d().direct_space_state.intersect_ray(from, to, exclude, mask)
+    /// if hit and hit.collider is T:
+    ///     ...
+    /// ```
+    fn raycast<T: SubClass<Node>>(
+        &self,
+        from: Vector2,
+        to: Vector2,
+        exclude: &[Rid],
+        mask: u32,
+    ) -> Option<RayHit<T, Vector2>>;
+}
+
+pub trait AreaExt {
+    /// Returns an iterator over the bodies in the area which can be cast to `T`. Bodies of any
+    /// other type are skipped. This is updated once per physics frame, so a body which just
+    /// entered may not be included yet. This has an explicit `unsafe` block. The unsafe code is
+    /// calling `assume_safe` on each body.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for body in get_overlapping_bodies():
+    ///     if body is T:
+    ///         ...
+    /// ```
+    fn overlapping_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'_, GroupNodes<'_>, T>;
+
+    /// Returns an iterator over the areas which overlap this one and can be cast to `T`. Same as
+    /// `overlapping_bodies_of_type`, but for areas. This has an explicit `unsafe` block. The unsafe
+    /// code is calling `assume_safe` on each area.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for area in get_overlapping_areas():
+    ///     if area is T:
+    ///         ...
+    /// ```
+    fn overlapping_areas_of_type<T: SubClass<Node>>(&self) -> OfType<'_, GroupNodes<'_>, T>;
+}
+
+pub trait RigidBodyExt {
+    /// Returns an iterator over the bodies this body is touching which can be cast to `T`. This
+    /// is empty unless `contact_monitor` is on and `contacts_reported` is more than zero. This
+    /// has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on each body.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for body in get_colliding_bodies():
+    ///     if body is T:
+    ///         ...
+    /// ```
+    fn colliding_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'_, GroupNodes<'_>, T>;
+}
+
+/// What a ray hit. See `PhysicsExt::raycast`. `V` is `Vector3` in 3D, and `Vector2` in 2D.
+pub struct RayHit<'a, T: SubClass<Node>, V> {
+    /// The body which was hit.
+    pub collider: TRef<'a, T, Shared>,
+    /// Where the ray hit the body, in global space.
+    pub position: V,
+    /// The normal of the surface the ray hit, in global space.
+    pub normal: V,
+    /// The index of the shape of `collider` which was hit.
+    pub shape: i64,
+}
+
+impl<'a, C: SubClass<Spatial>> PhysicsExt for TRef<'a, C> {
+    fn raycast<T: SubClass<Node>>(
+        &self,
+        from: Vector3,
+        to: Vector3,
+        exclude: &[Rid],
+        mask: u32,
+    ) -> Option<RayHit<'a, T, Vector3>> {
+        let world = self.upcast::<Spatial>().get_world()?;
+        let space = unsafe { world.assume_safe() }.direct_space_state()?;
+        let hit = unsafe { space.assume_safe() }.intersect_ray(
+            from,
+            to,
+            exclude_array(exclude),
+            i64::from(mask),
+            true,
+            false,
+        );
+        Some(RayHit {
+            collider: collider(&hit)?,
+            position: hit.get("position").try_to_vector3()?,
+            normal: hit.get("normal").try_to_vector3()?,
+            shape: hit.get("shape").to_i64(),
+        })
+    }
+}
+
+impl<'a, C: SubClass<Node2D>> Physics2DExt for TRef<'a, C> {
+    fn raycast<T: SubClass<Node>>(
+        &self,
+        from: Vector2,
+        to: Vector2,
+        exclude: &[Rid],
+        mask: u32,
+    ) -> Option<RayHit<'a, T, Vector2>> {
+        let world = self.upcast::<Node2D>().get_world_2d()?;
+        let space = unsafe { world.assume_safe() }.direct_space_state()?;
+        let hit = unsafe { space.assume_safe() }.intersect_ray(
+            from,
+            to,
+            exclude_array(exclude),
+            i64::from(mask),
+            true,
+            false,
+        );
+        Some(RayHit {
+            collider: collider(&hit)?,
+            position: hit.get("position").try_to_vector2()?,
+            normal: hit.get("normal").try_to_vector2()?,
+            shape: hit.get("shape").to_i64(),
+        })
+    }
+}
+
+impl<'a> AreaExt for TRef<'a, Area> {
+    fn overlapping_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_overlapping_bodies()))
+    }
+
+    fn overlapping_areas_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_overlapping_areas()))
+    }
+}
+
+impl<'a> AreaExt for TRef<'a, Area2D> {
+    fn overlapping_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_overlapping_bodies()))
+    }
+
+    fn overlapping_areas_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_overlapping_areas()))
+    }
+}
+
+impl<'a> RigidBodyExt for TRef<'a, RigidBody> {
+    fn colliding_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_colliding_bodies()))
+    }
+}
+
+impl<'a> RigidBodyExt for TRef<'a, RigidBody2D> {
+    fn colliding_bodies_of_type<T: SubClass<Node>>(&self) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_colliding_bodies()))
+    }
+}
+
+/// The `exclude` argument of `intersect_ray`.
+fn exclude_array(exclude: &[Rid]) -> VariantArray<Shared> {
+    exclude
+        .iter()
+        .collect::<VariantArray<Unique>>()
+        .into_shared()
+}
+
+/// The collider in the result of `intersect_ray`, if it is a `T`. This has an explicit `unsafe`
+/// block. The unsafe code is calling `assume_safe` on the collider.
+fn collider<'a, T: SubClass<Node>>(hit: &Dictionary) -> Option<TRef<'a, T, Shared>> {
+    let collider = hit.get("collider").try_to_object::<Node>()?;
+    unsafe { cast_node(collider) }.ok()
+}
@@ -1,6 +1,10 @@
-use crate::unsafe_functions::result_ext::ResultExt;
-use gdnative::prelude::{GodotString, PackedScene, ResourceLoader};
-use gdnative::Ref;
+use crate::godot_panic;
+use crate::unsafe_functions::interactive_load::InteractiveLoad;
+use gdnative::prelude::{GodotString, PackedScene, Resource, ResourceLoader, SubClass};
+use gdnative::ref_kind::RefCounted;
+use gdnative::{GodotObject, NewRef, Ref};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 pub trait ResourceLoaderExt {
     /// Tries to load a scene.
@@ -16,6 +20,85 @@ pub trait ResourceLoaderExt {
     /// Expects a scene at the given path and panics if it can not find it. See `try_get_scene` for
     /// a safer alternative. This method just unwraps the result.
     fn expect_load_scene(&self, path: impl Into<GodotString>) -> Ref<PackedScene>;
+
+    /// Tries to load a resource of type `T`. `T::class_name()` is passed as the type hint, so
+    /// only loaders for `T` are used. The resource is cached, so loading the same path again
+    /// returns the same resource.
+    ///
+    /// # Errors
+    /// - `LoadError::NotFound`: If there is no resource at the path.
+    /// - `LoadError::WrongType`: If the resource at the path is not a `T`.
+    /// - `LoadError::Failed`: If the resource exists, but could not be loaded. This is usually a
+    /// parse error, which Godot prints to the output.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// ResourceLoader.load(path, "T") as T
+    /// ```
+    fn try_load<T>(&self, path: impl Into<GodotString>) -> Result<Ref<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>;
+
+    /// Expects a resource of type `T` at the given path. Same as `try_load`, but panics instead of
+    /// returning an error.
+    /// # Panics
+    /// - If `try_load` returns an error.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// load(path) as T
+    /// ```
+    fn expect_load<T>(&self, path: impl Into<GodotString>) -> Ref<T>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>;
+
+    /// Loads a resource of type `T` without using the cache. This always loads a new copy of the
+    /// resource from disk. Same as `try_load` otherwise.
+    ///
+    /// # Errors
+    /// Same as `try_load`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// ResourceLoader.load(path, "T", true) as T
+    /// ```
+    fn load_uncached<T>(&self, path: impl Into<GodotString>) -> Result<Ref<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>;
+
+    /// Returns `true` if there is a resource at the given path and a loader which handles `T`.
+    /// This does not load the resource, so the actual type is not checked. The text loader
+    /// handles every type, so `exists_as::<Texture>` is `true` for a `.tscn` too. Use `try_load`
+    /// to check the type. It is not called `exists` because `ResourceLoader.exists` would be called
+    /// instead.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// ResourceLoader.exists(path, "T")
+    /// ```
+    fn exists_as<T>(&self, path: impl Into<GodotString>) -> bool
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>;
+
+    /// Starts loading a resource of type `T` in steps, so a loading screen can be shown while it
+    /// loads. Call `poll` on the returned `InteractiveLoad` every frame, and `finish` once it is
+    /// done. Use `LoadQueue` to load many resources at once.
+    ///
+    /// # Errors
+    /// - `LoadError::NotFound`: If there is no resource at the path.
+    /// - `LoadError::WrongType`: If the resource at the path can not be loaded as a `T`.
+    /// - `LoadError::Failed`: If Godot could not start loading the resource.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// ResourceLoader.load_interactive(path, "T")
+    /// ```
+    fn load_interactive_typed<T>(
+        &self,
+        path: impl Into<GodotString>,
+    ) -> Result<InteractiveLoad<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>;
 }
 
 impl ResourceLoaderExt for ResourceLoader {
@@ -23,19 +106,262 @@ impl ResourceLoaderExt for ResourceLoader {
         &self,
         path: impl Into<GodotString>,
     ) -> Result<Ref<PackedScene>, LoadSceneErr> {
-        self.load(path.into(), "", false)
-            .ok_or(LoadSceneErr::NoSuchScene)
-            .and_then(|x| x.cast::<PackedScene>().ok_or(LoadSceneErr::NotAScene))
+        let path = path.into();
+        self.load(path.new_ref(), "", false)
+            .ok_or_else(|| LoadSceneErr::NoSuchScene {
+                path: path.to_string(),
+            })
+            .and_then(|x| {
+                x.try_cast::<PackedScene>()
+                    .map_err(|x| LoadSceneErr::NotAScene {
+                        path: path.to_string(),
+                        actual: unsafe { x.assume_safe() }.get_class().to_string(),
+                    })
+            })
     }
 
     fn expect_load_scene(&self, path: impl Into<GodotString>) -> Ref<PackedScene> {
         self.try_load_scene(path)
-            .godot_expect("Failed to load scene")
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn try_load<T>(&self, path: impl Into<GodotString>) -> Result<Ref<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+    {
+        load_typed(self, path.into(), false)
+    }
+
+    fn expect_load<T>(&self, path: impl Into<GodotString>) -> Ref<T>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+    {
+        self.try_load(path)
+            .unwrap_or_else(|e| godot_panic!("{}", e))
+    }
+
+    fn load_uncached<T>(&self, path: impl Into<GodotString>) -> Result<Ref<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+    {
+        load_typed(self, path.into(), true)
+    }
+
+    fn exists_as<T>(&self, path: impl Into<GodotString>) -> bool
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+    {
+        self.exists(path, T::class_name())
+    }
+
+    fn load_interactive_typed<T>(
+        &self,
+        path: impl Into<GodotString>,
+    ) -> Result<InteractiveLoad<T>, LoadError>
+    where
+        T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+    {
+        let path = path.into();
+        check_loadable::<T>(self, &path)?;
+        self.load_interactive(path.new_ref(), T::class_name())
+            .map(|x| InteractiveLoad::new(path.to_string(), x))
+            .ok_or_else(|| LoadError::Failed {
+                path: path.to_string(),
+            })
+    }
+}
+
+/// Loads the resource at `path` as a `T`, and works out why it failed if it does.
+fn load_typed<T>(
+    loader: &ResourceLoader,
+    path: GodotString,
+    no_cache: bool,
+) -> Result<Ref<T>, LoadError>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    check_loadable::<T>(loader, &path)?;
+    let resource = loader
+        .load(path.new_ref(), T::class_name(), no_cache)
+        .ok_or_else(|| LoadError::Failed {
+            path: path.to_string(),
+        })?;
+    cast_resource(resource, &path.to_string())
+}
+
+/// Checks there is a resource at `path` which a loader for `T` recognizes.
+pub(crate) fn check_loadable<T>(
+    loader: &ResourceLoader,
+    path: &GodotString,
+) -> Result<(), LoadError>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    if !ResourceLoader::exists(loader, path.new_ref(), "") {
+        return Err(LoadError::NotFound {
+            path: path.to_string(),
+        });
     }
+    // A loader which does not recognize `T` will not even try to load the file.
+    if !ResourceLoader::exists(loader, path.new_ref(), T::class_name()) {
+        return Err(LoadError::WrongType {
+            path: path.to_string(),
+            expected: T::class_name(),
+            actual: None,
+        });
+    }
+    Ok(())
 }
 
-#[derive(Debug)]
+/// Casts a loaded resource to `T`.
+pub(crate) fn cast_resource<T>(resource: Ref<Resource>, path: &str) -> Result<Ref<T>, LoadError>
+where
+    T: SubClass<Resource> + GodotObject<RefKind = RefCounted>,
+{
+    resource.try_cast::<T>().map_err(|x| LoadError::WrongType {
+        path: path.to_string(),
+        expected: T::class_name(),
+        actual: Some(unsafe { x.assume_safe() }.get_class().to_string()),
+    })
+}
+
+/// An error when loading a scene with `ResourceLoaderExt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LoadSceneErr {
-    NoSuchScene,
-    NotAScene,
+    /// There is no scene at `path`, or it could not be loaded.
+    NoSuchScene { path: String },
+    /// The resource at `path` is an `actual`, not a `PackedScene`.
+    NotAScene { path: String, actual: String },
+}
+
+impl Display for LoadSceneErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadSceneErr::NoSuchScene { path } => write!(f, "Could not load a scene at {}", path),
+            LoadSceneErr::NotAScene { path, actual } => write!(
+                f,
+                "Expected the resource at {} to be a PackedScene, but it was a {}",
+                path, actual
+            ),
+        }
+    }
+}
+
+impl Error for LoadSceneErr {}
+
+/// An error when loading a resource with `ResourceLoaderExt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// There is no resource at `path`.
+    NotFound { path: String },
+    /// The resource at `path` is not an `expected`. `actual` is the class of the resource, if it
+    /// was loaded.
+    WrongType {
+        path: String,
+        expected: &'static str,
+        actual: Option<String>,
+    },
+    /// The resource at `path` exists, but could not be loaded.
+    Failed { path: String },
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::NotFound { path } => write!(f, "There is no resource at {}", path),
+            LoadError::WrongType {
+                path,
+                expected,
+                actual: Some(actual),
+            } => write!(
+                f,
+                "Expected the resource at {} to be a {}, but it was a {}",
+                path, expected, actual
+            ),
+            LoadError::WrongType {
+                path,
+                expected,
+                actual: None,
+            } => write!(
+                f,
+                "Expected the resource at {} to be a {}, but it can not be loaded as one",
+                path, expected
+            ),
+            LoadError::Failed { path } => write!(
+                f,
+                "Could not load the resource at {}. Check the output for parse errors",
+                path
+            ),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+#[cfg(test)]
+mod test {
+    use super::{LoadError, LoadSceneErr};
+
+    #[test]
+    fn load_scene_err() {
+        let error = LoadSceneErr::NoSuchScene {
+            path: "res://missing.tscn".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Could not load a scene at res://missing.tscn"
+        );
+        let error = LoadSceneErr::NotAScene {
+            path: "res://icon.png".to_string(),
+            actual: "StreamTexture".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the resource at res://icon.png to be a PackedScene, but it was a StreamTexture"
+        );
+    }
+
+    #[test]
+    fn load_error_not_found() {
+        let error = LoadError::NotFound {
+            path: "res://missing.tres".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "There is no resource at res://missing.tres"
+        );
+    }
+
+    #[test]
+    fn load_error_wrong_type() {
+        let error = LoadError::WrongType {
+            path: "res://icon.png".to_string(),
+            expected: "PackedScene",
+            actual: Some("StreamTexture".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the resource at res://icon.png to be a PackedScene, but it was a StreamTexture"
+        );
+        let error = LoadError::WrongType {
+            path: "res://icon.png".to_string(),
+            expected: "PackedScene",
+            actual: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected the resource at res://icon.png to be a PackedScene, but it can not be loaded as one"
+        );
+    }
+
+    #[test]
+    fn load_error_failed() {
+        let error = LoadError::Failed {
+            path: "res://broken.tscn".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Could not load the resource at res://broken.tscn. Check the output for parse errors"
+        );
+    }
 }
@@ -1,24 +1,53 @@
-use crate::godot_panic;
+use crate::unsafe_functions::{node_context, panic_at_caller};
+use gdnative::prelude::{Node, SubClass};
+use gdnative::TRef;
 use std::fmt::Debug;
 
 pub trait ResultExt<T> {
     /// Performs the exact same functionality as `Result::unwrap`, but prints errors to godot's
-    /// output also.
+    /// output also. The error points to the line which called this.
     fn godot_unwrap(self) -> T;
 
     /// Performs the exact same functionality as `Result::expect`, but prints errors to godot's
-    /// output also.
+    /// output also. The error points to the line which called this.
     fn godot_expect(self, msg: &str) -> T;
+
+    /// Same as `godot_expect`, but the error also says which node it happened in, with its path,
+    /// class and script. Pass the `owner` of the script calling this.
+    fn godot_expect_in<N: SubClass<Node>>(self, node: TRef<N>, msg: &str) -> T;
 }
 
+#[allow(clippy::single_match_else)] // To match Result's syntax
 impl<T, E: Debug> ResultExt<T> for Result<T, E> {
+    #[track_caller]
     fn godot_unwrap(self) -> T {
-        self.unwrap_or_else(|e| {
-            godot_panic!("called `Result::unwrap()` on an `Err` value: {:?}", &e)
-        })
+        match self {
+            Ok(x) => x,
+            Err(e) => panic_at_caller(&format!(
+                "called `Result::unwrap()` on an `Err` value: {:?}",
+                e
+            )),
+        }
     }
 
+    #[track_caller]
     fn godot_expect(self, msg: &str) -> T {
-        self.unwrap_or_else(|e| godot_panic!("{}: {:?}", msg, &e))
+        match self {
+            Ok(x) => x,
+            Err(e) => panic_at_caller(&format!("{}: {:?}", msg, e)),
+        }
+    }
+
+    #[track_caller]
+    fn godot_expect_in<N: SubClass<Node>>(self, node: TRef<N>, msg: &str) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => panic_at_caller(&format!(
+                "{} in {}: {:?}",
+                msg,
+                node_context(&node.upcast::<Node>()),
+                e
+            )),
+        }
     }
 }
@@ -0,0 +1,141 @@
+use crate::godot_panic;
+use crate::unsafe_functions::node_iter::{GroupNodes, Instances, OfType, TryOfType};
+use crate::unsafe_functions::result_ext::ResultExt;
+use gdnative::api::{SceneTree, SceneTreeTimer};
+use gdnative::nativescript::MapMut;
+use gdnative::prelude::{NativeClass, Node, Shared, SubClass};
+use gdnative::{Ref, TRef};
+
+pub trait SceneTreeExt {
+    /// Returns an iterator over the nodes in `group` which can be cast to `T`. Nodes of any other
+    /// type are skipped. Use `try_nodes_in_group` to find out about them instead. This has an
+    /// explicit `unsafe` block. The unsafe code is calling `assume_safe` on each node.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for node in get_tree().get_nodes_in_group(group):
+    ///     if node is T:
+    ///         ...
+    /// ```
+    fn nodes_in_group<T: SubClass<Node>>(&self, group: &str) -> OfType<'_, GroupNodes<'_>, T>;
+
+    /// Returns an iterator over the nodes in `group`. Same as `nodes_in_group`, but yields an error
+    /// for each node which is not a `T` instead of skipping it. This has an explicit `unsafe`
+    /// block. The unsafe code is calling `assume_safe` on each node.
+    /// # Errors
+    /// - `NodeLookupError::WrongType`: For each node in the group which is not the correct type.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for node in get_tree().get_nodes_in_group(group):
+    ///     assert(node is T)
+    /// ```
+    fn try_nodes_in_group<T: SubClass<Node>>(
+        &self,
+        group: &str,
+    ) -> TryOfType<'_, GroupNodes<'_>, T>;
+
+    /// Returns an iterator over the nodes in `group` which have the `T` script attached. This has an
+    /// explicit `unsafe` block. The unsafe code is calling `assume_safe` on each node.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for node in get_tree().get_nodes_in_group(group):
+    ///     if node is T:
+    ///         ...
+    /// ```
+    fn nodes_in_group_with_instance<T>(&self, group: &str) -> Instances<'_, GroupNodes<'_>, T>
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>;
+
+    /// Returns the first node in `group` which can be cast to `T`, or `None` if there is none.
+    /// This has an explicit `unsafe` block. The unsafe code is calling `assume_safe` on each node.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// for node in get_tree().get_nodes_in_group(group):
+    ///     if node is T:
+    ///         return node
+    /// return null
+    /// ```
+    fn first_in_group<T: SubClass<Node>>(&self, group: &str) -> Option<TRef<T>>;
+
+    /// Calls `f` on every node in `group` which has the `T` script attached, and returns how many
+    /// nodes it was called on. Unlike `call_group`, this is checked at compile time. This has an
+    /// explicit `unsafe` block, and can panic. The unsafe code is calling `assume_safe` on each
+    /// node.
+    /// # Panics
+    /// - If the script of one of the nodes is already borrowed.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_tree().call_group(group, "method")
+    /// ```
+    fn call_group_typed<T, F>(&self, group: &str, f: F) -> usize
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>,
+        T::UserData: MapMut,
+        F: FnMut(&mut T, TRef<T::Base>);
+
+    /// Creates a timer which times out once after `seconds`, without adding a `Timer` node. Use
+    /// `SceneTreeTimerExt::on_timeout` to connect to it. The timer keeps running while the game is
+    /// paused. The scene tree holds on to the timer until it times out, so the returned reference
+    /// can be dropped.
+    /// # Panics
+    /// - If Godot could not create the timer.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// get_tree().create_timer(seconds)
+    /// ```
+    fn create_one_shot_timer(&self, seconds: f64) -> Ref<SceneTreeTimer, Shared>;
+}
+
+impl<'a> SceneTreeExt for TRef<'a, SceneTree, Shared> {
+    fn nodes_in_group<T: SubClass<Node>>(&self, group: &str) -> OfType<'a, GroupNodes<'a>, T> {
+        OfType::new(GroupNodes::new(self.get_nodes_in_group(group)))
+    }
+
+    fn try_nodes_in_group<T: SubClass<Node>>(
+        &self,
+        group: &str,
+    ) -> TryOfType<'a, GroupNodes<'a>, T> {
+        TryOfType::new(GroupNodes::new(self.get_nodes_in_group(group)))
+    }
+
+    fn nodes_in_group_with_instance<T>(&self, group: &str) -> Instances<'a, GroupNodes<'a>, T>
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>,
+    {
+        Instances::new(GroupNodes::new(self.get_nodes_in_group(group)))
+    }
+
+    fn first_in_group<T: SubClass<Node>>(&self, group: &str) -> Option<TRef<'a, T, Shared>> {
+        self.nodes_in_group(group).next()
+    }
+
+    fn call_group_typed<T, F>(&self, group: &str, mut f: F) -> usize
+    where
+        T: NativeClass,
+        T::Base: SubClass<Node>,
+        T::UserData: MapMut,
+        F: FnMut(&mut T, TRef<T::Base>),
+    {
+        let mut count = 0;
+        for instance in self.nodes_in_group_with_instance::<T>(group) {
+            instance
+                .map_mut(|x, owner| f(x, owner))
+                .godot_expect("Could not mutably borrow the script");
+            count += 1;
+        }
+        count
+    }
+
+    fn create_one_shot_timer(&self, seconds: f64) -> Ref<SceneTreeTimer, Shared> {
+        self.create_timer(seconds, true)
+            .unwrap_or_else(|| godot_panic!("Could not create a timer"))
+    }
+}
@@ -1,9 +1,12 @@
 use crate::unsafe_functions::vector3_ext::Vector3Ext;
 use gdnative::api::Spatial;
 use gdnative::object::SubClass;
-use gdnative::prelude::{Basis, Vector3};
+use gdnative::prelude::{Basis, Quat, Transform, Vector3};
 use gdnative::TRef;
 
+/// Squared lengths below this are treated as zero. This is the same as Godot's `CMP_EPSILON`.
+const EPSILON: f32 = 0.00001;
+
 pub trait SpatialExt {
     /// Sets the local rotation of the Spatial.
     ///
@@ -37,25 +40,42 @@ pub trait SpatialExt {
     /// ```
     fn set_global_origin(&self, origin: Vector3);
 
-    /// Returns the unit vector representing the direction the `Spatial` is facing. Godot has some
-    /// [ambiguity](https://github.com/godotengine/godot/issues/15283) regarding the forward vector,
-    /// so we are using (0, 1, 0), or Z+ forward.
+    /// Returns the unit vector representing the direction the `Spatial` is facing in global space.
+    /// This is Z- forward, the direction cameras and `look_at` face.
+    ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// spatial.global_transform.xform(Vector3(0, 0, 1))
+    /// -spatial.global_transform.basis.z
     /// ```
     fn global_forward(&self) -> Vector3;
 
-    /// Returns the unit vector representing the direction the `Spatial` is facing. Godot has some
-    /// [ambiguity](https://github.com/godotengine/godot/issues/15283) regarding the forward vector,
-    /// so we are using (0, 1, 0), or Z+ forward.
+    /// Returns the unit vector representing the direction the `Spatial` is facing relative to its
+    /// parent. This is Z- forward, the direction cameras and `look_at` face.
     ///
     /// # GdScript Equivalent
     /// ```gdscript
-    /// spatial.transform.xform(Vector3(0, 0, 1))
+    /// -spatial.transform.basis.z
     /// ```
     fn forward(&self) -> Vector3;
 
+    /// Returns the unit vector representing back relative to the `Spatial`'s global orientation.
+    /// This is the opposite of `global_forward`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.global_transform.basis.z
+    /// ```
+    fn global_back(&self) -> Vector3;
+
+    /// Returns the unit vector representing back relative to the `Spatial`'s local orientation.
+    /// This is the opposite of `forward`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.transform.basis.z
+    /// ```
+    fn back(&self) -> Vector3;
+
     /// Returns the unit vector representing up relative to the `Spatial`'s global orientation.
     ///
     /// # GdScript Equivalent
@@ -72,6 +92,22 @@ pub trait SpatialExt {
     /// ```
     fn up(&self) -> Vector3;
 
+    /// Returns the unit vector representing down relative to the `Spatial`'s global orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// -spatial.global_transform.basis.y
+    /// ```
+    fn global_down(&self) -> Vector3;
+
+    /// Returns the unit vector representing down relative to the `Spatial`'s local orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// -spatial.transform.basis.y
+    /// ```
+    fn down(&self) -> Vector3;
+
     /// Returns the unit vector representing right relative to the `Spatial`'s global orientation.
     ///
     /// # GdScript Equivalent
@@ -87,6 +123,97 @@ pub trait SpatialExt {
     /// spatial.transform.xform(Vector3(1, 0, 0))
     /// ```
     fn right(&self) -> Vector3;
+
+    /// Returns the unit vector representing left relative to the `Spatial`'s global orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// -spatial.global_transform.basis.x
+    /// ```
+    fn global_left(&self) -> Vector3;
+
+    /// Returns the unit vector representing left relative to the `Spatial`'s local orientation.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// -spatial.transform.basis.x
+    /// ```
+    fn left(&self) -> Vector3;
+
+    /// Rotates the `Spatial` so its forward vector points at `target`, keeping its scale. Unlike
+    /// `look_at`, an `up` which is collinear with the direction to `target` does not fail. The
+    /// current forward vector is used as up instead, so a camera looking straight down keeps its
+    /// heading. If that is collinear too, Z and then Y are used. Returns `false` and does nothing
+    /// if `target` is at the `Spatial`'s origin.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.look_at(target, up)
+    /// ```
+    fn look_at_safe(&self, target: Vector3, up: Vector3) -> bool;
+
+    /// Rotates the `Spatial`'s forward vector toward `target` by at most `max_radians`. Call this
+    /// every frame with `speed * delta` to turn at a fixed speed. Returns `true` once it faces
+    /// `target`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var forward = -spatial.global_transform.basis.z
+    /// var direction = spatial.global_transform.origin.direction_to(target)
+    /// var angle = min(forward.angle_to(direction), max_radians)
+    /// spatial.global_rotate(forward.cross(direction).normalized(), angle)
+    /// ```
+    fn rotate_toward(&self, target: Vector3, max_radians: f32) -> bool;
+
+    /// Rotates the `Spatial` so its forward vector points along `direction`, using Y as up. See
+    /// `look_at_safe`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.look_at(spatial.global_transform.origin + direction, Vector3.UP)
+    /// ```
+    fn face_direction(&self, direction: Vector3) -> bool;
+
+    /// Spherically interpolates the global rotation toward `basis` by `weight`, keeping the scale
+    /// and origin.
+    /// # Panics
+    /// - If the scale of the `Spatial` or `basis` is zero on any axis.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var from = spatial.global_transform.basis.get_rotation_quat()
+    /// var to = basis.get_rotation_quat()
+    /// spatial.global_transform.basis = Basis(from.slerp(to, weight)).scaled(scale)
+    /// ```
+    fn slerp_global_rotation(&self, basis: Basis, weight: f32);
+
+    /// Interpolates the global transform toward `target` by `weight`. The rotation is spherically
+    /// interpolated, and the origin and scale are linearly interpolated.
+    /// # Panics
+    /// - If the scale of the `Spatial` or `target` is zero on any axis.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.global_transform = spatial.global_transform.interpolate_with(target, weight)
+    /// ```
+    fn interpolate_transform_to(&self, target: Transform, weight: f32);
+
+    /// Returns the distance between the global origins of this `Spatial` and `other`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.global_transform.origin.distance_to(other.global_transform.origin)
+    /// ```
+    fn distance_to<S: SubClass<Spatial>>(&self, other: TRef<S>) -> f32;
+
+    /// Returns the unit vector pointing from the global origin of this `Spatial` to the global
+    /// origin of `other`. Returns zero if they are at the same place.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// spatial.global_transform.origin.direction_to(other.global_transform.origin)
+    /// ```
+    fn direction_to<S: SubClass<Spatial>>(&self, other: TRef<S>) -> Vector3;
 }
 
 impl<'a, Class: SubClass<Spatial>> SpatialExt for TRef<'a, Class> {
@@ -132,6 +259,20 @@ impl<'a, Class: SubClass<Spatial>> SpatialExt for TRef<'a, Class> {
             .xform(Vector3::forward())
     }
 
+    fn global_back(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .global_transform()
+            .basis
+            .xform(Vector3::back())
+    }
+
+    fn back(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .transform()
+            .basis
+            .xform(Vector3::back())
+    }
+
     fn global_up(&self) -> Vector3 {
         self.upcast::<Spatial>()
             .global_transform()
@@ -146,6 +287,20 @@ impl<'a, Class: SubClass<Spatial>> SpatialExt for TRef<'a, Class> {
             .xform(Vector3::up())
     }
 
+    fn global_down(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .global_transform()
+            .basis
+            .xform(Vector3::down())
+    }
+
+    fn down(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .transform()
+            .basis
+            .xform(Vector3::down())
+    }
+
     fn global_right(&self) -> Vector3 {
         self.upcast::<Spatial>()
             .global_transform()
@@ -159,4 +314,252 @@ impl<'a, Class: SubClass<Spatial>> SpatialExt for TRef<'a, Class> {
             .basis
             .xform(Vector3::right())
     }
+
+    fn global_left(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .global_transform()
+            .basis
+            .xform(Vector3::left())
+    }
+
+    fn left(&self) -> Vector3 {
+        self.upcast::<Spatial>()
+            .transform()
+            .basis
+            .xform(Vector3::left())
+    }
+
+    fn look_at_safe(&self, target: Vector3, up: Vector3) -> bool {
+        let spatial = self.upcast::<Spatial>();
+        let mut transform = spatial.global_transform();
+        let forward = -transform.basis.z();
+        match looking_at(target - transform.origin, &fallback_ups(up, forward)) {
+            Some(rotation) => {
+                transform.basis = with_scale(rotation, scale_of(&transform.basis));
+                spatial.set_global_transform(transform);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn rotate_toward(&self, target: Vector3, max_radians: f32) -> bool {
+        let spatial = self.upcast::<Spatial>();
+        let mut transform = spatial.global_transform();
+        let direction = target - transform.origin;
+        if direction.square_length() < EPSILON {
+            return true;
+        }
+        let direction = direction.normalize();
+        let forward = (-transform.basis.z()).normalize();
+        let angle = forward.angle_to(direction).radians;
+        if angle < EPSILON {
+            return true;
+        }
+        let mut axis = forward.cross(direction);
+        if axis.square_length() < EPSILON {
+            // `target` is directly behind, so any perpendicular axis works. Turning around the
+            // `Spatial`'s own up axis keeps it upright.
+            axis = transform.basis.y();
+        }
+        transform.basis = transform
+            .basis
+            .rotated(axis.normalize(), angle.min(max_radians));
+        spatial.set_global_transform(transform);
+        angle <= max_radians
+    }
+
+    fn face_direction(&self, direction: Vector3) -> bool {
+        let origin = self.upcast::<Spatial>().global_transform().origin;
+        self.look_at_safe(origin + direction, Vector3::up())
+    }
+
+    fn slerp_global_rotation(&self, basis: Basis, weight: f32) {
+        let spatial = self.upcast::<Spatial>();
+        let mut transform = spatial.global_transform();
+        let rotation = slerp_rotation(&transform.basis, &basis, weight);
+        transform.basis = with_scale(rotation, scale_of(&transform.basis));
+        spatial.set_global_transform(transform);
+    }
+
+    fn interpolate_transform_to(&self, target: Transform, weight: f32) {
+        let spatial = self.upcast::<Spatial>();
+        let mut transform = spatial.global_transform();
+        let rotation = slerp_rotation(&transform.basis, &target.basis, weight);
+        let scale = scale_of(&transform.basis).lerp(scale_of(&target.basis), weight);
+        transform.basis = with_scale(rotation, scale);
+        transform.origin = transform.origin.lerp(target.origin, weight);
+        spatial.set_global_transform(transform);
+    }
+
+    fn distance_to<S: SubClass<Spatial>>(&self, other: TRef<S>) -> f32 {
+        let from = self.upcast::<Spatial>().global_transform().origin;
+        let to = other.upcast::<Spatial>().global_transform().origin;
+        (to - from).length()
+    }
+
+    fn direction_to<S: SubClass<Spatial>>(&self, other: TRef<S>) -> Vector3 {
+        let from = self.upcast::<Spatial>().global_transform().origin;
+        let to = other.upcast::<Spatial>().global_transform().origin;
+        let direction = to - from;
+        if direction.square_length() < EPSILON {
+            Vector3::zero()
+        } else {
+            direction.normalize()
+        }
+    }
+}
+
+/// Returns a rotation whose forward vector points along `direction`. The first of `ups` which is
+/// not collinear with `direction` is used as up. Returns `None` if `direction` is zero.
+fn looking_at(direction: Vector3, ups: &[Vector3]) -> Option<Basis> {
+    if direction.square_length() < EPSILON {
+        return None;
+    }
+    let z = -direction.normalize();
+    let x = ups
+        .iter()
+        .map(|up| up.cross(z))
+        .find(|x| x.square_length() >= EPSILON)?
+        .normalize();
+    let y = z.cross(x);
+    let mut basis = Basis::identity();
+    basis.set_x(x);
+    basis.set_y(y);
+    basis.set_z(z);
+    Some(basis)
+}
+
+/// The up vectors `look_at_safe` tries in order. `forward` and Z are collinear with the same
+/// directions when the `Spatial` faces along Z, so Y is tried last.
+fn fallback_ups(up: Vector3, forward: Vector3) -> [Vector3; 4] {
+    [up, forward, Vector3::back(), Vector3::up()]
+}
+
+/// Spherically interpolates between the rotations of `from` and `to`, ignoring their scale.
+fn slerp_rotation(from: &Basis, to: &Basis, weight: f32) -> Basis {
+    let from = from.orthonormalized().to_quat();
+    let to = to.orthonormalized().to_quat();
+    from_quat(from.slerp(&to, weight))
+}
+
+/// Converts a quaternion to a rotation. This is the inverse of `Basis::to_quat`.
+fn from_quat(quat: Quat) -> Basis {
+    let (x, y, z, w) = (quat.i, quat.j, quat.k, quat.r);
+    let s = 2.0 / (x * x + y * y + z * z + w * w);
+    let (xs, ys, zs) = (x * s, y * s, z * s);
+    let (wx, wy, wz) = (w * xs, w * ys, w * zs);
+    let (xx, xy, xz) = (x * xs, x * ys, x * zs);
+    let (yy, yz, zz) = (y * ys, y * zs, z * zs);
+    Basis::from_elements([
+        Vector3::new(1.0 - (yy + zz), xy - wz, xz + wy),
+        Vector3::new(xy + wz, 1.0 - (xx + zz), yz - wx),
+        Vector3::new(xz - wy, yz + wx, 1.0 - (xx + yy)),
+    ])
+}
+
+/// The length of each axis of `basis`.
+fn scale_of(basis: &Basis) -> Vector3 {
+    Vector3::new(basis.x().length(), basis.y().length(), basis.z().length())
+}
+
+/// Scales each axis of `rotation` by `scale`.
+fn with_scale(rotation: Basis, scale: Vector3) -> Basis {
+    let mut basis = rotation;
+    basis.set_x(rotation.x() * scale.x);
+    basis.set_y(rotation.y() * scale.y);
+    basis.set_z(rotation.z() * scale.z);
+    basis
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fallback_ups, from_quat, looking_at, scale_of, slerp_rotation, with_scale};
+    use crate::unsafe_functions::vector3_ext::Vector3Ext;
+    use gdnative::prelude::{Basis, Vector3};
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_near(actual: Vector3, expected: Vector3) {
+        assert!(
+            (actual - expected).length() < 1e-6,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn identity_faces_negative_z() {
+        let basis = Basis::identity();
+        assert_near(
+            basis.xform(Vector3::forward()),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert_near(basis.xform(Vector3::back()), Vector3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn positive_yaw_turns_left() {
+        // A positive rotation around Y is counter-clockwise seen from above, so forward turns left.
+        let basis = Basis::from_axis_angle(&Vector3::up(), FRAC_PI_2);
+        assert_near(basis.xform(Vector3::forward()), Vector3::left());
+        assert_near(basis.xform(Vector3::right()), Vector3::forward());
+        assert_near(basis.xform(Vector3::up()), Vector3::up());
+    }
+
+    #[test]
+    fn looking_at_faces_direction() {
+        let basis = looking_at(Vector3::right(), &[Vector3::up()]).unwrap();
+        assert_near(basis.xform(Vector3::forward()), Vector3::right());
+        assert_near(basis.xform(Vector3::up()), Vector3::up());
+    }
+
+    #[test]
+    fn looking_at_collinear_up() {
+        assert!(looking_at(Vector3::down(), &[Vector3::up()]).is_none());
+        let basis = looking_at(Vector3::down(), &[Vector3::up(), Vector3::forward()]).unwrap();
+        assert_near(basis.xform(Vector3::forward()), Vector3::down());
+        assert_near(basis.xform(Vector3::up()), Vector3::forward());
+    }
+
+    #[test]
+    fn looking_at_forward_and_up_collinear() {
+        let ups = fallback_ups(Vector3::back(), Vector3::forward());
+        let basis = looking_at(Vector3::back(), &ups).unwrap();
+        assert_near(basis.xform(Vector3::forward()), Vector3::back());
+        assert_near(basis.xform(Vector3::up()), Vector3::up());
+    }
+
+    #[test]
+    fn looking_at_zero() {
+        assert!(looking_at(Vector3::zero(), &[Vector3::up()]).is_none());
+    }
+
+    #[test]
+    fn from_quat_round_trip() {
+        let basis = Basis::from_axis_angle(&Vector3::new(1.0, 2.0, 3.0).normalize(), 1.0);
+        let round_trip = from_quat(basis.to_quat());
+        for axis in &[Vector3::right(), Vector3::up(), Vector3::back()] {
+            assert_near(round_trip.xform(*axis), basis.xform(*axis));
+        }
+    }
+
+    #[test]
+    fn slerp_rotation_halfway() {
+        let to = Basis::from_axis_angle(&Vector3::up(), FRAC_PI_2);
+        let halfway = slerp_rotation(&Basis::identity(), &to, 0.5);
+        let expected = Basis::from_axis_angle(&Vector3::up(), FRAC_PI_2 / 2.0);
+        assert_near(
+            halfway.xform(Vector3::forward()),
+            expected.xform(Vector3::forward()),
+        );
+    }
+
+    #[test]
+    fn with_scale_keeps_rotation() {
+        let rotation = Basis::from_axis_angle(&Vector3::up(), FRAC_PI_2);
+        let scaled = with_scale(rotation, Vector3::new(2.0, 3.0, 4.0));
+        assert_near(scale_of(&scaled), Vector3::new(2.0, 3.0, 4.0));
+        assert_near(scaled.xform(Vector3::forward()), Vector3::left() * 4.0);
+    }
 }
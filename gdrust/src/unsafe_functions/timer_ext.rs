@@ -0,0 +1,54 @@
+use crate::godot_panic;
+use gdnative::api::SceneTreeTimer;
+use gdnative::prelude::{Object, Shared, SubClass, Unique, Variant, VariantArray};
+use gdnative::{Ref, TRef};
+
+pub trait SceneTreeTimerExt {
+    /// Calls `method` on `target` with `args` when the timer times out. Unlike `connect`, the
+    /// signal name can not be misspelled, and a missing method panics here instead of failing
+    /// silently when the timer times out. This has an explicit `unsafe` block. The unsafe code is
+    /// calling `assume_safe` on the timer.
+    /// # Panics
+    /// - If `target` does not have `method`.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// timer.connect("timeout", target, method, args)
+    /// ```
+    fn on_timeout<T: SubClass<Object>>(&self, target: TRef<T>, method: &str, args: &[Variant]);
+}
+
+impl SceneTreeTimerExt for Ref<SceneTreeTimer, Shared> {
+    fn on_timeout<T: SubClass<Object>>(&self, target: TRef<T>, method: &str, args: &[Variant]) {
+        // The scene tree holds on to the timer until it times out.
+        let timer = unsafe { self.assume_safe() };
+        connect_checked(timer.upcast(), "timeout", target.upcast(), method, args, 0);
+    }
+}
+
+/// Connects `signal` of `source` to `method` of `target`, with `args` bound after the arguments of
+/// the signal.
+/// # Panics
+/// - If `target` does not have `method`.
+/// - If the signal could not be connected.
+pub(crate) fn connect_checked(
+    source: TRef<Object>,
+    signal: &str,
+    target: TRef<Object>,
+    method: &str,
+    args: &[Variant],
+    flags: i64,
+) {
+    if !target.has_method(method) {
+        godot_panic!(
+            "Can not connect {} to {}, since the {} does not have it",
+            signal,
+            method,
+            target.get_class()
+        );
+    }
+    let binds = args.iter().collect::<VariantArray<Unique>>().into_shared();
+    source
+        .connect(signal, target, method, binds, flags)
+        .unwrap_or_else(|e| godot_panic!("Could not connect {} to {}: {:?}", signal, method, e));
+}
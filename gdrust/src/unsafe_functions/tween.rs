@@ -0,0 +1,240 @@
+use crate::unsafe_functions::timer_ext::connect_checked;
+use crate::{godot_assert, godot_panic};
+use gdnative::api::Tween;
+use gdnative::prelude::{Node, Object, Shared, SubClass, ToVariant, Variant};
+use gdnative::TRef;
+
+/// Builds a `Tween` which animates properties, and frees itself once it is done. Create one with
+/// `NodeExt::create_tween`. Each call to `tween_property` adds a property, and `from`, `trans`,
+/// `ease` and `delay` change the property added last:
+/// ```rust,ignore
+/// owner
+///     .create_tween()
+///     .tween_property(sprite, "position", Vector2::new(100.0, 0.0), 0.5)
+///     .trans(Transition::Quad)
+///     .ease(Ease::Out)
+///     .tween_property(sprite, "modulate:a", 0.0, 0.25)
+///     .delay(0.5)
+///     .start();
+/// ```
+pub struct TweenBuilder<'a> {
+    parent: TRef<'a, Node, Shared>,
+    properties: Vec<PropertyTween<'a>>,
+}
+
+struct PropertyTween<'a> {
+    target: TRef<'a, Object, Shared>,
+    property: String,
+    from: Option<Variant>,
+    to: Variant,
+    duration: f64,
+    trans: Transition,
+    ease: Ease,
+    delay: f64,
+}
+
+impl<'a> TweenBuilder<'a> {
+    pub(crate) fn new(parent: TRef<'a, Node, Shared>) -> Self {
+        Self {
+            parent,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Animates `property` of `target` from its current value to `to` over `duration` seconds.
+    /// `property` can be a sub-property, like `"position:x"`. The current value is read when the
+    /// tween starts, so if this builder already animates the same property, it starts where the
+    /// previous one ends instead.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// tween.interpolate_property(target, property, target.get_indexed(property), to, duration)
+    /// ```
+    pub fn tween_property<T: SubClass<Object>>(
+        mut self,
+        target: TRef<'a, T, Shared>,
+        property: &str,
+        to: impl ToVariant,
+        duration: f64,
+    ) -> Self {
+        self.properties.push(PropertyTween {
+            target: target.upcast(),
+            property: property.to_string(),
+            from: None,
+            to: to.to_variant(),
+            duration,
+            trans: Transition::default(),
+            ease: Ease::default(),
+            delay: 0.0,
+        });
+        self
+    }
+
+    /// Starts the last property at `from` instead of its current value.
+    pub fn from(mut self, from: impl ToVariant) -> Self {
+        self.last().from = Some(from.to_variant());
+        self
+    }
+
+    /// Sets the transition of the last property. This is `Transition::Linear` by default.
+    pub fn trans(mut self, trans: Transition) -> Self {
+        self.last().trans = trans;
+        self
+    }
+
+    /// Sets the easing of the last property. This is `Ease::InOut` by default.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.last().ease = ease;
+        self
+    }
+
+    /// Waits `seconds` before animating the last property. Use this to animate properties one after
+    /// another.
+    pub fn delay(mut self, seconds: f64) -> Self {
+        self.last().delay = seconds;
+        self
+    }
+
+    /// Adds a `Tween` to the parent, starts it, and returns it. The `Tween` frees itself once every
+    /// property is done. This has an explicit `unsafe` block. The unsafe code is calling
+    /// `assume_safe` on the new `Tween` once it is owned by the parent.
+    /// # Panics
+    /// - If no properties were added.
+    ///
+    /// # GdScript Equivalent
+    /// ```gdscript
+    /// var tween = Tween.new()
+    /// add_child(tween)
+    /// tween.interpolate_property(...)
+    /// tween.connect("tween_all_completed", tween, "queue_free")
+    /// tween.start()
+    /// ```
+    pub fn start(self) -> TRef<'a, Tween, Shared> {
+        godot_assert!(
+            !self.properties.is_empty(),
+            "Call tween_property before starting a tween"
+        );
+        // The tween is owned by `parent` from here on, so it lives until it frees itself.
+        let tween = unsafe { Tween::new().into_shared().assume_safe() };
+        self.parent.add_child(tween, false);
+        for (i, property) in self.properties.iter().enumerate() {
+            let from = property
+                .from
+                .clone()
+                .or_else(|| previous_to(&self.properties[..i], property))
+                .unwrap_or_else(|| property.target.get_indexed(property.property.as_str()));
+            tween.interpolate_property(
+                property.target,
+                property.property.as_str(),
+                from,
+                property.to.clone(),
+                property.duration,
+                property.trans.value(),
+                property.ease.value(),
+                property.delay,
+            );
+        }
+        connect_checked(
+            tween.upcast(),
+            "tween_all_completed",
+            tween.upcast(),
+            "queue_free",
+            &[],
+            Object::CONNECT_ONESHOT,
+        );
+        tween.start();
+        tween
+    }
+
+    fn last(&mut self) -> &mut PropertyTween<'a> {
+        self.properties
+            .last_mut()
+            .unwrap_or_else(|| godot_panic!("Call tween_property before configuring it"))
+    }
+}
+
+/// The value the last of `previous` which animates the same property as `property` ends at. A
+/// delayed tween of a property which is already animated must start there, since the current value
+/// is read before any of them run.
+fn previous_to(previous: &[PropertyTween], property: &PropertyTween) -> Option<Variant> {
+    let id = property.target.get_instance_id();
+    previous
+        .iter()
+        .rev()
+        .find(|x| x.target.get_instance_id() == id && x.property == property.property)
+        .map(|x| x.to.clone())
+}
+
+/// How a tweened value moves from start to end. See `TweenBuilder::trans`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    Linear,
+    Sine,
+    Quint,
+    Quart,
+    Quad,
+    Expo,
+    Elastic,
+    Cubic,
+    Circ,
+    Bounce,
+    Back,
+}
+
+#[allow(clippy::derivable_impls)] // #[default] on a variant needs Rust 1.62
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::Linear
+    }
+}
+
+impl Transition {
+    /// The value Godot uses for this transition.
+    pub fn value(self) -> i64 {
+        match self {
+            Transition::Linear => Tween::TRANS_LINEAR,
+            Transition::Sine => Tween::TRANS_SINE,
+            Transition::Quint => Tween::TRANS_QUINT,
+            Transition::Quart => Tween::TRANS_QUART,
+            Transition::Quad => Tween::TRANS_QUAD,
+            Transition::Expo => Tween::TRANS_EXPO,
+            Transition::Elastic => Tween::TRANS_ELASTIC,
+            Transition::Cubic => Tween::TRANS_CUBIC,
+            Transition::Circ => Tween::TRANS_CIRC,
+            Transition::Bounce => Tween::TRANS_BOUNCE,
+            Transition::Back => Tween::TRANS_BACK,
+        }
+    }
+}
+
+/// Which end of a tween the transition is applied to. See `TweenBuilder::ease`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ease {
+    /// Starts slowly and speeds up.
+    In,
+    /// Starts quickly and slows down.
+    Out,
+    /// Slow at both ends, and fast in the middle.
+    InOut,
+    /// Fast at both ends, and slow in the middle.
+    OutIn,
+}
+
+#[allow(clippy::derivable_impls)] // #[default] on a variant needs Rust 1.62
+impl Default for Ease {
+    fn default() -> Self {
+        Ease::InOut
+    }
+}
+
+impl Ease {
+    /// The value Godot uses for this easing.
+    pub fn value(self) -> i64 {
+        match self {
+            Ease::In => Tween::EASE_IN,
+            Ease::Out => Tween::EASE_OUT,
+            Ease::InOut => Tween::EASE_IN_OUT,
+            Ease::OutIn => Tween::EASE_OUT_IN,
+        }
+    }
+}
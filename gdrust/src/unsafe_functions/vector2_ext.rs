@@ -1,16 +1,34 @@
 use gdnative::prelude::Vector2;
 
+/// Unit vectors for Godot's 2D coordinate system. Y points down in 2D, so up is `(0, -1)`.
 pub trait Vector2Ext {
-    /// Returns the UP unit vector: (0, 1).
+    /// Returns the UP unit vector: (0, -1).
     fn up() -> Vector2;
 
+    /// Returns the DOWN unit vector: (0, 1).
+    fn down() -> Vector2;
+
     /// Returns the RIGHT unit vector: (1, 0)
     fn right() -> Vector2;
+
+    /// Returns the LEFT unit vector: (-1, 0)
+    fn left() -> Vector2;
+
+    /// Returns the ZERO vector: (0, 0)
+    fn zero() -> Vector2;
+
+    /// Returns the ONE vector: (1, 1)
+    fn one() -> Vector2;
 }
 
 impl Vector2Ext for Vector2 {
     #[inline]
     fn up() -> Vector2 {
+        Vector2::new(0.0, -1.0)
+    }
+
+    #[inline]
+    fn down() -> Vector2 {
         Vector2::new(0.0, 1.0)
     }
 
@@ -18,4 +36,51 @@ impl Vector2Ext for Vector2 {
     fn right() -> Vector2 {
         Vector2::new(1.0, 0.0)
     }
+
+    #[inline]
+    fn left() -> Vector2 {
+        Vector2::new(-1.0, 0.0)
+    }
+
+    #[inline]
+    fn zero() -> Vector2 {
+        Vector2::new(0.0, 0.0)
+    }
+
+    #[inline]
+    fn one() -> Vector2 {
+        Vector2::new(1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Vector2Ext;
+    use gdnative::prelude::Vector2;
+
+    #[test]
+    fn vector2_directions() {
+        assert_eq!(<Vector2 as Vector2Ext>::up(), Vector2::new(0.0, -1.0));
+        assert_eq!(<Vector2 as Vector2Ext>::down(), Vector2::new(0.0, 1.0));
+        assert_eq!(<Vector2 as Vector2Ext>::right(), Vector2::new(1.0, 0.0));
+        assert_eq!(<Vector2 as Vector2Ext>::left(), Vector2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn vector2_opposites() {
+        assert_eq!(
+            -<Vector2 as Vector2Ext>::up(),
+            <Vector2 as Vector2Ext>::down()
+        );
+        assert_eq!(
+            -<Vector2 as Vector2Ext>::right(),
+            <Vector2 as Vector2Ext>::left()
+        );
+    }
+
+    #[test]
+    fn vector2_zero_one() {
+        assert_eq!(<Vector2 as Vector2Ext>::zero(), Vector2::new(0.0, 0.0));
+        assert_eq!(<Vector2 as Vector2Ext>::one(), Vector2::new(1.0, 1.0));
+    }
 }
@@ -1,14 +1,31 @@
 use gdnative::prelude::Vector3;
 
+/// Unit vectors for Godot's 3D coordinate system. Godot is right-handed with Y up, and cameras and
+/// `look_at` face -Z, so forward is `(0, 0, -1)`.
 pub trait Vector3Ext {
     /// Returns the UP unit vector: (0, 1, 0).
     fn up() -> Vector3;
 
-    /// Returns the FORWARD unit vector: (0, 0, 1).
+    /// Returns the DOWN unit vector: (0, -1, 0).
+    fn down() -> Vector3;
+
+    /// Returns the FORWARD unit vector: (0, 0, -1).
     fn forward() -> Vector3;
 
+    /// Returns the BACK unit vector: (0, 0, 1).
+    fn back() -> Vector3;
+
     /// Returns the RIGHT unit vector: (1, 0, 0).
     fn right() -> Vector3;
+
+    /// Returns the LEFT unit vector: (-1, 0, 0).
+    fn left() -> Vector3;
+
+    /// Returns the ZERO vector: (0, 0, 0).
+    fn zero() -> Vector3;
+
+    /// Returns the ONE vector: (1, 1, 1).
+    fn one() -> Vector3;
 }
 
 impl Vector3Ext for Vector3 {
@@ -17,8 +34,18 @@ impl Vector3Ext for Vector3 {
         Vector3::new(0.0, 1.0, 0.0)
     }
 
+    #[inline]
+    fn down() -> Vector3 {
+        Vector3::new(0.0, -1.0, 0.0)
+    }
+
     #[inline]
     fn forward() -> Vector3 {
+        Vector3::new(0.0, 0.0, -1.0)
+    }
+
+    #[inline]
+    fn back() -> Vector3 {
         Vector3::new(0.0, 0.0, 1.0)
     }
 
@@ -26,4 +53,62 @@ impl Vector3Ext for Vector3 {
     fn right() -> Vector3 {
         Vector3::new(1.0, 0.0, 0.0)
     }
+
+    #[inline]
+    fn left() -> Vector3 {
+        Vector3::new(-1.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    fn zero() -> Vector3 {
+        Vector3::new(0.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    fn one() -> Vector3 {
+        Vector3::new(1.0, 1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Vector3Ext;
+    use gdnative::prelude::Vector3;
+
+    #[test]
+    fn vector3_directions() {
+        assert_eq!(<Vector3 as Vector3Ext>::up(), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            <Vector3 as Vector3Ext>::down(),
+            Vector3::new(0.0, -1.0, 0.0)
+        );
+        assert_eq!(
+            <Vector3 as Vector3Ext>::forward(),
+            Vector3::new(0.0, 0.0, -1.0)
+        );
+        assert_eq!(<Vector3 as Vector3Ext>::back(), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(
+            <Vector3 as Vector3Ext>::right(),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            <Vector3 as Vector3Ext>::left(),
+            Vector3::new(-1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn vector3_right_handed() {
+        // In a right-handed system, right x up is back, not forward.
+        let right = <Vector3 as Vector3Ext>::right();
+        let up = <Vector3 as Vector3Ext>::up();
+        assert_eq!(right.cross(up), <Vector3 as Vector3Ext>::back());
+        assert_eq!(up.cross(right), <Vector3 as Vector3Ext>::forward());
+    }
+
+    #[test]
+    fn vector3_zero_one() {
+        assert_eq!(<Vector3 as Vector3Ext>::zero(), Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(<Vector3 as Vector3Ext>::one(), Vector3::new(1.0, 1.0, 1.0));
+    }
 }
@@ -0,0 +1,139 @@
+use heck::ShoutySnakeCase;
+use std::collections::HashSet;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, token, Expr, Ident, ItemStruct, Result, Token, Type};
+
+/// Removes the `#[constant]` attributes from `item` and parses them. Every attribute is removed
+/// even if one of them does not parse, so the errors are the only ones reported.
+#[allow(clippy::module_name_repetitions)]
+pub fn extract_constants(item: &mut ItemStruct) -> Result<Vec<ConstantDecl>> {
+    let mut result = Vec::new();
+    let mut error: Option<syn::Error> = None;
+    item.attrs = item
+        .attrs
+        .iter()
+        .filter(|attr| {
+            attr.path
+                .get_ident()
+                .filter(|x| *x == "constant")
+                .map(|_x| match attr.parse_args::<ConstantDecl>() {
+                    Ok(constant) => result.push(constant),
+                    Err(e) => match &mut error {
+                        Some(error) => error.combine(e),
+                        None => error = Some(e),
+                    },
+                })
+                .is_none()
+        })
+        .cloned()
+        .collect();
+    match error {
+        Some(error) => Err(error),
+        None => {
+            check_constant_names(&result)?;
+            Ok(result)
+        }
+    }
+}
+
+/// Value constants and enums are both registered as properties, and value constants and enum
+/// variants are both associated consts of the struct, so each of those names must be unique.
+fn check_constant_names(constants: &[ConstantDecl]) -> Result<()> {
+    let mut properties = HashSet::new();
+    let mut consts = HashSet::new();
+    for constant in constants {
+        match constant {
+            ConstantDecl::Value(value) => {
+                unique(&mut properties, &value.name, &value.name)?;
+                unique(&mut consts, &value.name, &value.name)?;
+            }
+            ConstantDecl::Enum(decl) => {
+                unique(&mut properties, &decl.name, &decl.name)?;
+                let mut variants = HashSet::new();
+                for variant in &decl.variants {
+                    unique(&mut variants, &variant.name, &variant.name)?;
+                    unique(&mut consts, &decl.variant_const(variant), &variant.name)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds `name` to `names`, or returns an error at `span` if it is already there.
+fn unique(names: &mut HashSet<String>, name: &Ident, span: &Ident) -> Result<()> {
+    if names.insert(name.to_string()) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            span.span(),
+            format!("The constant `{}` is declared more than once", name),
+        ))
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum ConstantDecl {
+    /// `#[constant(NAME: Type = value)]`
+    Value(ValueConstantDecl),
+    /// `#[constant(Name { A, B = 5 })]`
+    Enum(EnumConstantDecl),
+}
+
+pub struct ValueConstantDecl {
+    pub name: Ident,
+    pub ty: Type,
+    pub value: Expr,
+}
+
+pub struct EnumConstantDecl {
+    pub name: Ident,
+    pub variants: Punctuated<EnumVariantDecl, Token![,]>,
+}
+
+impl EnumConstantDecl {
+    /// The associated const of `variant`. It is prefixed with the name of the enum, like
+    /// `STATE_IDLE` for `State { IDLE }`, so different enums can have variants with the same name.
+    pub fn variant_const(&self, variant: &EnumVariantDecl) -> Ident {
+        let prefix = self.name.to_string().to_shouty_snake_case();
+        Ident::new(&format!("{}_{}", prefix, variant.name), variant.name.span())
+    }
+}
+
+pub struct EnumVariantDecl {
+    pub name: Ident,
+    pub value: Option<(Token![=], Expr)>,
+}
+
+impl Parse for ConstantDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        if input.peek(token::Brace) {
+            let content;
+            let _brace_token = braced!(content in input);
+            let variants = content.parse_terminated(EnumVariantDecl::parse)?;
+            Ok(Self::Enum(EnumConstantDecl { name, variants }))
+        } else {
+            let _colon = input.parse::<Token![:]>()?;
+            let ty = input.parse()?;
+            let _eq = input.parse::<Token![=]>()?;
+            let value = input.parse()?;
+            Ok(Self::Value(ValueConstantDecl { name, ty, value }))
+        }
+    }
+}
+
+impl Parse for EnumVariantDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            let eq = input.parse()?;
+            let value = input.parse()?;
+            Some((eq, value))
+        } else {
+            None
+        };
+        Ok(Self { name, value })
+    }
+}
@@ -0,0 +1,207 @@
+use crate::compiler::constants::ConstantDecl;
+use crate::compiler::properties::{ExportType, Property};
+use crate::compiler::signals::SignalDecl;
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// The environment variable which enables dumping the expansion of every `#[gdrust]` class.
+const EXPAND_DIR_VAR: &str = "GDRUST_EXPAND_DIR";
+
+/// Writes the generated code for a class to `{dir}/{ClassName}.rs` if `#[gdrust(debug_expand)]`
+/// was used or `GDRUST_EXPAND_DIR` is set. The file is formatted with `rustfmt` when it is
+/// available. The expansion is only a debugging aid, so a failure to write it is printed instead
+/// of failing the build.
+pub(crate) fn write_expansion(
+    class: &Ident,
+    properties: &[Property],
+    signals: &[SignalDecl],
+    constants: &[ConstantDecl],
+    compiled: &TokenStream,
+    debug_expand: bool,
+) {
+    let dir = match std::env::var_os(EXPAND_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None if debug_expand => default_dir(),
+        None => return,
+    };
+    let path = dir.join(format!("{}.rs", class));
+    let contents = format!(
+        "{}\n{}\n",
+        header(class, properties, signals, constants),
+        compiled
+    );
+    let written = std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, contents));
+    if let Err(e) = written {
+        eprintln!(
+            "warning: could not write the expansion of {} to {}: {}",
+            class,
+            path.display(),
+            e
+        );
+        return;
+    }
+    // Formatting is best effort. An unformatted expansion is still useful.
+    let _ = std::process::Command::new("rustfmt")
+        .arg("--edition=2018")
+        .arg(&path)
+        .output();
+}
+
+/// `gdrust_expand` in the target directory. That is `CARGO_TARGET_DIR` if it is set, and otherwise
+/// `target` next to the `Cargo.lock` of the workspace. Proc macros do not get `OUT_DIR`, and the
+/// crate's own directory only has a `target` if it is not part of a workspace.
+fn default_dir() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("CARGO_MANIFEST_DIR")
+                .map(|x| workspace_dir(&PathBuf::from(x)).join("target"))
+        })
+        .unwrap_or_else(|| PathBuf::from("target"));
+    target.join("gdrust_expand")
+}
+
+/// The first directory from `manifest_dir` up with a `Cargo.lock`, which is the root of the
+/// workspace, or `manifest_dir` if there is none.
+fn workspace_dir(manifest_dir: &Path) -> PathBuf {
+    manifest_dir
+        .ancestors()
+        .find(|x| x.join("Cargo.lock").is_file())
+        .unwrap_or(manifest_dir)
+        .to_path_buf()
+}
+
+fn header(
+    class: &Ident,
+    properties: &[Property],
+    signals: &[SignalDecl],
+    constants: &[ConstantDecl],
+) -> String {
+    let mut header = format!("// Generated by #[gdrust] for `{}`.\n", class);
+    header.push_str("//\n// Properties:\n");
+    for property in properties {
+        let default = property
+            .default
+            .as_ref()
+            .map_or_else(|| "Default::default()".to_string(), tokens);
+        let ty = match &property.conversion {
+            Some(conversion) => format!("{} as {}", tokens(&property.ty), tokens(&conversion.ty)),
+            None => tokens(&property.ty),
+        };
+        let _ = writeln!(
+            header,
+            "//   {}: {} = {} [{}]",
+            property.name,
+            ty,
+            default,
+            describe_export(&property.export_type)
+        );
+    }
+    header.push_str("//\n// Signals:\n");
+    for signal in signals {
+        let args = signal
+            .args
+            .iter()
+            .map(|x| match &x.default {
+                Some((_, default)) => {
+                    format!("{}: {} = {}", x.name, tokens(&x.ty), tokens(default))
+                }
+                None => format!("{}: {}", x.name, tokens(&x.ty)),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let _ = writeln!(header, "//   {}({})", signal.name, args);
+    }
+    header.push_str("//\n// Constants:\n");
+    for constant in constants {
+        let _ = match constant {
+            ConstantDecl::Value(value) => writeln!(
+                header,
+                "//   {}: {} = {}",
+                value.name,
+                tokens(&value.ty),
+                tokens(&value.value)
+            ),
+            ConstantDecl::Enum(decl) => {
+                let variants = decl
+                    .variants
+                    .iter()
+                    .map(|x| x.name.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                writeln!(header, "//   {} {{ {} }}", decl.name, variants)
+            }
+        };
+    }
+    header
+}
+
+fn describe_export(export: &ExportType) -> String {
+    match export {
+        ExportType::NoHint => "no hint".to_string(),
+        ExportType::NoExport => "no_export".to_string(),
+        ExportType::Export => "export".to_string(),
+        ExportType::ExportRange(x) => format!("export_range({})", tokens(&x.range)),
+        ExportType::ExportExpRange(x) => format!("export_exp_range({})", tokens(&x.range)),
+        ExportType::ExportEnum(x) => format!("export_enum({})", tokens(&x.values)),
+        ExportType::ExportFile(x) => match &x.filter {
+            Some((_, filter)) => format!("export_file({})", tokens(filter)),
+            None => "export_file".to_string(),
+        },
+        ExportType::ExportDir => "export_dir".to_string(),
+        ExportType::ExportGlobalFile(x) => match &x.filter {
+            Some((_, filter)) => format!("export_global_file({})", tokens(filter)),
+            None => "export_global_file".to_string(),
+        },
+        ExportType::ExportGlobalDir => "export_global_dir".to_string(),
+        ExportType::ExportMultiline => "export_multiline".to_string(),
+        ExportType::ExportColorNoAlpha => "export_color_no_alpha".to_string(),
+        ExportType::ExportNodePath(x) => match &x.types {
+            Some((_, types)) => format!("export_node_path({})", tokens(types)),
+            None => "export_node_path".to_string(),
+        },
+        ExportType::ExportFlags(x) => format!("export_flags({})", tokens(&x.values)),
+        ExportType::ExportFlags2dPhysics => "export_flags_2d_physics".to_string(),
+        ExportType::ExportFlags2dRender => "export_flags_2d_render".to_string(),
+        ExportType::ExportFlags3dPhysics => "export_flags_3d_physics".to_string(),
+        ExportType::ExportFlags3dRender => "export_flags_3d_render".to_string(),
+    }
+}
+
+fn tokens<T: ToTokens>(x: &T) -> String {
+    x.to_token_stream().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::workspace_dir;
+    use std::path::PathBuf;
+
+    /// A new empty directory in the temporary directory, unique to this process.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gdrust_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)
+            .unwrap_or_else(|e| panic!("Could not create {}: {}", dir.display(), e));
+        dir
+    }
+
+    #[test]
+    fn workspace_dir_of_member() {
+        let workspace = temp_dir("workspace");
+        let member = workspace.join("examples").join("game");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(workspace.join("Cargo.lock"), "").unwrap();
+        assert_eq!(workspace_dir(&member), workspace);
+        std::fs::remove_dir_all(&workspace).unwrap();
+    }
+
+    #[test]
+    fn workspace_dir_without_lock() {
+        let dir = temp_dir("no_lock");
+        assert_eq!(workspace_dir(&dir), dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,6 @@
+use crate::compiler::constants::ConstantDecl;
 use crate::compiler::hints::property_hint;
+use crate::compiler::metadata::metadata_fn;
 use crate::compiler::properties::{ExportType, Property};
 use crate::compiler::signal_args::create_signal_arg;
 use crate::compiler::signals::SignalDecl;
@@ -10,21 +12,33 @@ use syn::{Expr, ItemStruct};
 pub(crate) fn impl_block(
     properties: &[Property],
     signals: &[SignalDecl],
+    constants: &[ConstantDecl],
     extends: &Extends,
+    metadata: bool,
     item: &ItemStruct,
 ) -> TokenStream {
     let struct_name = &item.ident;
+    // The metadata refers to `gdrust`, so it is only generated when asked for. Everything else only
+    // needs `gdnative`.
+    let metadata = if metadata {
+        metadata_fn(properties, signals, constants, extends, struct_name)
+    } else {
+        TokenStream::new()
+    };
     let extends = &extends.ty;
 
     let property_inits = property_inits(properties);
-    let register_properties = register_properties(properties, signals);
+    let register_properties = register_properties(properties, signals, constants);
     let signal_consts = signal_consts(signals);
+    let constant_consts = constant_consts(constants);
 
     quote::quote! {
         impl #struct_name {
 
             #(#signal_consts)*
 
+            #(#constant_consts)*
+
             #[allow(clippy::default_trait_access)]
             fn new(_owner: gdnative::prelude::TRef<#extends>) -> Self {
                 Self {
@@ -33,6 +47,8 @@ pub(crate) fn impl_block(
             }
 
             #register_properties
+
+            #metadata
         }
     }
 }
@@ -48,15 +64,22 @@ fn property_inits(properties: &[Property]) -> Vec<TokenStream> {
         .collect()
 }
 
-fn register_properties(properties: &[Property], signals: &[SignalDecl]) -> TokenStream {
+fn register_properties(
+    properties: &[Property],
+    signals: &[SignalDecl],
+    constants: &[ConstantDecl],
+) -> TokenStream {
     let properties: Vec<TokenStream> = properties.iter().map(|x| builder_for_property(x)).collect();
     let signals: Vec<TokenStream> = signals.iter().map(|x| builder_for_signal(x)).collect();
+    let constants: Vec<TokenStream> = constants.iter().map(builder_for_constant).collect();
     quote::quote! {
         #[allow(clippy::default_trait_access)]
         fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
             #(#properties)*
 
             #(#signals)*
+
+            #(#constants)*
         }
     }
 }
@@ -65,18 +88,41 @@ fn builder_for_property(property: &Property) -> TokenStream {
     if let ExportType::NoExport = property.export_type {
         return quote::quote! {};
     }
-    let ty = &property.ty;
+    let ty = property.export_ty();
     let ident = &property.name;
     let default = &property.default;
     let ident_str = ident.to_string();
-    let hint = property_hint(&property.export_type, &property.ty);
-    let setter = quote::quote! { .with_setter(|this, _owner, val| {
+    let hint = property_hint(&property.export_type, ty);
+    let mut setter = quote::quote! { .with_setter(|this, _owner, val| {
         this.#ident = val
     })};
-    let getter = quote::quote! { .with_ref_getter(|this, _owner| {
+    let mut getter = quote::quote! { .with_ref_getter(|this, _owner| {
         &this.#ident
     })};
-    let default = get_default(default.as_ref());
+    let mut default = get_default(default.as_ref());
+    if let Some(conversion) = &property.conversion {
+        let rust_ty = &property.ty;
+        let from = conversion.from.as_ref().map_or_else(
+            || quote::quote! { <#rust_ty as std::convert::From<#ty>>::from },
+            |x| quote::quote! { #x },
+        );
+        let into = |value: TokenStream| {
+            conversion.into.as_ref().map_or_else(
+                || quote::quote! {
+                    <#ty as std::convert::From<#rust_ty>>::from(std::clone::Clone::clone(#value))
+                },
+                |x| quote::quote! { (#x)(#value) },
+            )
+        };
+        let get = into(quote::quote! { &this.#ident });
+        setter = quote::quote! { .with_setter(|this, _owner, val: #ty| {
+            this.#ident = (#from)(val)
+        })};
+        getter = quote::quote! { .with_getter(|this, _owner| {
+            #get
+        })};
+        default = into(quote::quote! { &#default });
+    }
     quote::quote! {
         builder.add_property::<#ty>(#ident_str)
             #hint
@@ -100,6 +146,79 @@ fn builder_for_signal(signal: &SignalDecl) -> TokenStream {
     }
 }
 
+// NativeScript has no notion of script constants, so constants are registered as properties
+// without a setter. They are neither stored nor shown in the inspector.
+fn builder_for_constant(constant: &ConstantDecl) -> TokenStream {
+    match constant {
+        ConstantDecl::Value(value) => {
+            let name = &value.name;
+            let name_str = name.to_string();
+            let ty = &value.ty;
+            quote::quote! {
+                builder.add_property::<#ty>(#name_str)
+                    .with_getter(|_this, _owner| Self::#name)
+                    .with_usage(gdnative::nativescript::PropertyUsage::empty())
+                    .done();
+            }
+        }
+        ConstantDecl::Enum(decl) => {
+            let name_str = decl.name.to_string();
+            let inserts = decl.variants.iter().map(|x| {
+                let variant = decl.variant_const(x);
+                let variant_str = x.name.to_string();
+                quote::quote! { dict.insert(#variant_str, Self::#variant); }
+            });
+            quote::quote! {
+                builder.add_property::<gdnative::core_types::Dictionary>(#name_str)
+                    .with_getter(|_this, _owner| {
+                        let dict = gdnative::core_types::Dictionary::new();
+                        #(#inserts)*
+                        dict.into_shared()
+                    })
+                    .with_usage(gdnative::nativescript::PropertyUsage::empty())
+                    .done();
+            }
+        }
+    }
+}
+
+fn constant_consts(constants: &[ConstantDecl]) -> Vec<TokenStream> {
+    constants
+        .iter()
+        .flat_map(|x| match x {
+            ConstantDecl::Value(value) => {
+                let name = &value.name;
+                let ty = &value.ty;
+                let expr = &value.value;
+                vec![quote::quote! {
+                    pub const #name: #ty = #expr;
+                }]
+            }
+            ConstantDecl::Enum(decl) => {
+                // Mirrors GdScript: each variant is the previous variant plus one, unless a value
+                // is given explicitly.
+                let mut previous: Option<Ident> = None;
+                decl.variants
+                    .iter()
+                    .map(|variant| {
+                        let name = decl.variant_const(variant);
+                        let value = match (&variant.value, &previous) {
+                            (Some((_, expr)), _) => quote::quote! { #expr },
+                            (None, Some(previous)) => quote::quote! { Self::#previous + 1 },
+                            (None, None) => quote::quote! { 0 },
+                        };
+                        let constant = quote::quote! {
+                            pub const #name: i64 = #value;
+                        };
+                        previous = Some(name);
+                        constant
+                    })
+                    .collect()
+            }
+        })
+        .collect()
+}
+
 fn signal_consts(signals: &[SignalDecl]) -> Vec<TokenStream> {
     signals
         .iter()
@@ -0,0 +1,306 @@
+use crate::compiler::constants::{ConstantDecl, EnumVariantDecl};
+use crate::compiler::properties::{ExportType, Property};
+use crate::compiler::signals::SignalDecl;
+use crate::Extends;
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{Lit, LitStr, Token};
+
+/// Generates `__gdrust_metadata()`, which describes the same properties and signals as
+/// `__register_properties_and_signals` with plain data.
+pub(crate) fn metadata_fn(
+    properties: &[Property],
+    signals: &[SignalDecl],
+    constants: &[ConstantDecl],
+    extends: &Extends,
+    name: &syn::Ident,
+) -> TokenStream {
+    let name_str = name.to_string();
+    let base = source(&extends.ty);
+    let properties = properties
+        .iter()
+        .filter(|x| !matches!(x.export_type, ExportType::NoExport))
+        .map(property_metadata)
+        .chain(constants.iter().map(constant_metadata));
+    let signals = signals.iter().map(signal_metadata);
+    quote::quote! {
+        /// Describes what this class registers with Godot, without needing a running engine.
+        pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+            const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+                name: #name_str,
+                base: #base,
+                properties: &[#(#properties,)*],
+                signals: &[#(#signals,)*],
+            };
+            METADATA
+        }
+    }
+}
+
+fn property_metadata(property: &Property) -> TokenStream {
+    let name = property.name.to_string();
+    let rust_type = source(&property.ty);
+    let export_type = source(property.export_ty());
+    let hint = hint(&property.export_type);
+    let default = property.default.as_ref().map_or_else(
+        || quote::quote! { None },
+        |default| {
+            let default = source(default);
+            quote::quote! { Some(#default) }
+        },
+    );
+    quote::quote! {
+        gdrust::metadata::PropertyMetadata {
+            name: #name,
+            rust_type: #rust_type,
+            export_type: #export_type,
+            hint: #hint,
+            default: #default,
+            usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+        }
+    }
+}
+
+fn constant_metadata(constant: &ConstantDecl) -> TokenStream {
+    let (name, ty, value) = match constant {
+        ConstantDecl::Value(value) => (
+            value.name.to_string(),
+            source(&value.ty),
+            source(&value.value),
+        ),
+        ConstantDecl::Enum(decl) => (
+            decl.name.to_string(),
+            "Dictionary".to_string(),
+            enum_description(&decl.variants),
+        ),
+    };
+    quote::quote! {
+        gdrust::metadata::PropertyMetadata {
+            name: #name,
+            rust_type: #ty,
+            export_type: #ty,
+            hint: gdrust::metadata::PropertyHint::None,
+            default: Some(#value),
+            usage: gdnative::nativescript::PropertyUsage::empty(),
+        }
+    }
+}
+
+/// Describes an enum like the dictionary it is registered as, such as `{ A: 0, B: 5 }`. The values
+/// are numbered like `constant_consts`: each variant is the previous variant plus one, unless a
+/// value is given explicitly.
+fn enum_description(variants: &Punctuated<EnumVariantDecl, Token![,]>) -> String {
+    let mut previous: Option<(String, Option<i64>)> = None;
+    let variants = variants
+        .iter()
+        .map(|variant| {
+            let (text, number) = match (&variant.value, &previous) {
+                (Some((_, expr)), _) => {
+                    let text = source(expr);
+                    let number = text.parse::<i64>().ok();
+                    (text, number)
+                }
+                (None, Some((_, Some(number)))) => ((number + 1).to_string(), Some(number + 1)),
+                (None, Some((text, None))) => (format!("{} + 1", text), None),
+                (None, None) => ("0".to_string(), Some(0)),
+            };
+            let described = format!("{}: {}", variant.name, text);
+            previous = Some((text, number));
+            described
+        })
+        .collect::<Vec<String>>();
+    format!("{{ {} }}", variants.join(", "))
+}
+
+fn signal_metadata(signal: &SignalDecl) -> TokenStream {
+    let name = signal.name.to_string();
+    let args = signal.args.iter().map(|arg| {
+        let arg_name = arg.name.to_string();
+        let ty = source(&arg.ty);
+        let default = arg.default.as_ref().map_or_else(
+            || quote::quote! { None },
+            |(_, default)| {
+                let default = source(default);
+                quote::quote! { Some(#default) }
+            },
+        );
+        quote::quote! {
+            gdrust::metadata::SignalArgMetadata {
+                name: #arg_name,
+                ty: #ty,
+                default: #default,
+            }
+        }
+    });
+    quote::quote! {
+        gdrust::metadata::SignalMetadata {
+            name: #name,
+            args: &[#(#args,)*],
+        }
+    }
+}
+
+fn hint(export: &ExportType) -> TokenStream {
+    let variant = match export {
+        ExportType::NoHint | ExportType::NoExport | ExportType::Export => quote::quote! { None },
+        ExportType::ExportRange(x) => {
+            let range = range(&x.range);
+            quote::quote! { Range(#range) }
+        }
+        ExportType::ExportExpRange(x) => {
+            let range = range(&x.range);
+            quote::quote! { ExpRange(#range) }
+        }
+        ExportType::ExportEnum(x) => {
+            let values = x.values.iter();
+            quote::quote! { Enum(&[#(#values),*]) }
+        }
+        ExportType::ExportFile(x) => {
+            let filter = x.filter.iter().map(|(_, filter)| filter);
+            quote::quote! { File(&[#(#filter),*]) }
+        }
+        ExportType::ExportDir => quote::quote! { Dir },
+        ExportType::ExportGlobalFile(x) => {
+            let filter = x.filter.iter().map(|(_, filter)| filter);
+            quote::quote! { GlobalFile(&[#(#filter),*]) }
+        }
+        ExportType::ExportGlobalDir => quote::quote! { GlobalDir },
+        ExportType::ExportMultiline => quote::quote! { Multiline },
+        ExportType::ExportColorNoAlpha => quote::quote! { ColorNoAlpha },
+        ExportType::ExportNodePath(x) => {
+            let types = x
+                .types
+                .iter()
+                .flat_map(|(_, types)| types.iter())
+                .map(|x| LitStr::new(&source(x), Span::call_site()));
+            quote::quote! { NodePath(&[#(#types),*]) }
+        }
+        ExportType::ExportFlags(x) => {
+            let values = x.values.iter();
+            quote::quote! { Flags(&[#(#values),*]) }
+        }
+        ExportType::ExportFlags2dPhysics => quote::quote! { Layers2DPhysics },
+        ExportType::ExportFlags2dRender => quote::quote! { Layers2DRender },
+        ExportType::ExportFlags3dPhysics => quote::quote! { Layers3DPhysics },
+        ExportType::ExportFlags3dRender => quote::quote! { Layers3DRender },
+    };
+    quote::quote! { gdrust::metadata::PropertyHint::#variant }
+}
+
+/// The range has already been checked by `property_hint`, so anything unexpected is skipped here.
+fn range(range: &Punctuated<Lit, Token![,]>) -> TokenStream {
+    let mut values = range.iter();
+    let min = values.next().and_then(number);
+    let max = values.next().and_then(number);
+    let mut step = quote::quote! { None };
+    let mut or_lesser = false;
+    let mut or_greater = false;
+    for value in values {
+        match value {
+            Lit::Str(x) if x.value() == "or_lesser" => or_lesser = true,
+            Lit::Str(x) if x.value() == "or_greater" => or_greater = true,
+            x => {
+                if let Some(x) = number(x) {
+                    step = quote::quote! { Some(#x) };
+                }
+            }
+        }
+    }
+    quote::quote! {
+        gdrust::metadata::RangeHint {
+            min: #min,
+            max: #max,
+            step: #step,
+            or_lesser: #or_lesser,
+            or_greater: #or_greater,
+        }
+    }
+}
+
+fn number(lit: &Lit) -> Option<Literal> {
+    let value = match lit {
+        Lit::Int(x) => x.base10_parse::<f64>().ok()?,
+        Lit::Float(x) => x.base10_parse::<f64>().ok()?,
+        _ => return None,
+    };
+    Some(Literal::f64_unsuffixed(value))
+}
+
+/// The source of `x`, without most of the spaces `TokenStream` puts between every token. A space
+/// is kept between two words, like in `dyn Trait`, after commas, around `->` and inside strings.
+fn source<T: ToTokens>(x: &T) -> String {
+    let spaced = x.to_token_stream().to_string();
+    let chars = spaced.chars().collect::<Vec<char>>();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_' || *c == '\'' || *c == '"';
+    let mut result = String::with_capacity(spaced.len());
+    let mut in_string = false;
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '"' && (i == 0 || chars[i - 1] != '\\') {
+            in_string = !in_string;
+        }
+        if *c == ' ' && !in_string {
+            let before = &chars[..i];
+            let after = &chars[i + 1..];
+            let between_words = match (before.last(), after.first()) {
+                (Some(a), Some(b)) => is_word(a) && is_word(b),
+                _ => false,
+            };
+            let keep = between_words
+                || before.ends_with(&[','])
+                || before.ends_with(&['-', '>'])
+                || after.starts_with(&['-', '>']);
+            if !keep {
+                continue;
+            }
+        }
+        result.push(*c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{enum_description, source};
+    use crate::compiler::constants::EnumVariantDecl;
+    use syn::punctuated::Punctuated;
+    use syn::{parse_quote, Expr, Token, Type};
+
+    #[test]
+    fn type_without_spaces() {
+        let ty: Type = parse_quote! { Option<Ref<Node, Shared>> };
+        assert_eq!(source(&ty), "Option<Ref<Node, Shared>>");
+        let ty: Type = parse_quote! { &'static str };
+        assert_eq!(source(&ty), "&'static str");
+        let ty: Type = parse_quote! { Box<dyn Fn(i64) -> i64> };
+        assert_eq!(source(&ty), "Box<dyn Fn(i64) -> i64>");
+    }
+
+    #[test]
+    fn expression_without_spaces() {
+        let expr: Expr = parse_quote! { Health(100.0) };
+        assert_eq!(source(&expr), "Health(100.0)");
+        let expr: Expr = parse_quote! { "a - b".to_string() };
+        assert_eq!(source(&expr), "\"a - b\".to_string()");
+    }
+
+    #[test]
+    fn enum_numbered_like_consts() {
+        let variants: Punctuated<EnumVariantDecl, Token![,]> =
+            parse_quote! { IDLE, RUN = 5, JUMP, FALL = -1, LAND };
+        assert_eq!(
+            enum_description(&variants),
+            "{ IDLE: 0, RUN: 5, JUMP: 6, FALL: -1, LAND: 0 }"
+        );
+    }
+
+    #[test]
+    fn enum_with_expression_value() {
+        let variants: Punctuated<EnumVariantDecl, Token![,]> =
+            parse_quote! { A = Self::BASE * 2, B };
+        assert_eq!(
+            enum_description(&variants),
+            "{ A: Self::BASE*2, B: Self::BASE*2 + 1 }"
+        );
+    }
+}
@@ -1,18 +1,35 @@
+mod constants;
+mod explain;
 mod hints;
 mod impl_block;
+mod metadata;
 mod properties;
 mod signal_args;
 mod signals;
 
+use crate::compiler::constants::extract_constants;
 use crate::compiler::properties::extract_properties;
 use crate::compiler::signals::extract_signals;
-use crate::Extends;
+use crate::GdrustArgs;
 use proc_macro2::TokenStream;
 use syn::{parse_quote, ItemStruct};
 
-pub(crate) fn compile(item: &mut ItemStruct, extends: &Extends) -> TokenStream {
+pub(crate) fn compile(item: &mut ItemStruct, args: &GdrustArgs) -> TokenStream {
     let signals = extract_signals(item);
+    let constants = extract_constants(item);
     let properties = extract_properties(item);
+    let constants = match constants {
+        Ok(x) => x,
+        Err(e) => {
+            let error = e.to_compile_error();
+            return quote::quote! {
+                #item
+
+                #error
+            };
+        }
+    };
+    let extends = &args.extends;
     let extends_type = &extends.ty;
     item.attrs
         .push(parse_quote! { #[derive(gdnative::NativeClass)] });
@@ -20,10 +37,297 @@ pub(crate) fn compile(item: &mut ItemStruct, extends: &Extends) -> TokenStream {
     item.attrs
         .push(parse_quote! { #[register_with(Self::__register_properties_and_signals)] });
 
-    let impl_block = impl_block::impl_block(&properties, &signals, extends, item);
-    quote::quote! {
+    let impl_block = impl_block::impl_block(
+        &properties,
+        &signals,
+        &constants,
+        extends,
+        args.metadata,
+        item,
+    );
+    let compiled = quote::quote! {
         #item
 
         #impl_block
+    };
+    explain::write_expansion(
+        &item.ident,
+        &properties,
+        &signals,
+        &constants,
+        &compiled,
+        args.debug_expand,
+    );
+    compiled
+}
+
+#[cfg(test)]
+mod test {
+    use super::compile;
+    use crate::GdrustArgs;
+    use proc_macro2::TokenStream;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::process::{Command, Stdio};
+    use syn::{parse_quote, ItemStruct};
+
+    /// Compiles `item` with `__gdrust_metadata()`, which describes every hint, and compares the
+    /// formatted result with `snapshots/{name}.rs`.
+    fn assert_snapshot(name: &str, item: ItemStruct) {
+        let args: GdrustArgs = parse_quote! { extends = gdnative::api::Node, metadata };
+        assert_snapshot_with_args(name, &args, item);
+    }
+
+    /// Compiles `item` and compares the formatted result with `snapshots/{name}.rs`. Run with
+    /// `GDRUST_BLESS=1` to write the current output instead, after checking that the change is
+    /// intended.
+    fn assert_snapshot_with_args(name: &str, args: &GdrustArgs, mut item: ItemStruct) {
+        let actual = format(&compile(&mut item, args));
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/compiler/snapshots")
+            .join(format!("{}.rs", name));
+        if std::env::var_os("GDRUST_BLESS").is_some() {
+            std::fs::write(&path, actual)
+                .unwrap_or_else(|e| panic!("Could not write {}: {}", path.display(), e));
+            return;
+        }
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e));
+        assert_eq!(
+            actual,
+            expected,
+            "The expansion no longer matches {}",
+            path.display()
+        );
+    }
+
+    /// Formats the expansion with `rustfmt`, so the snapshots can be read and diffed.
+    fn format(tokens: &TokenStream) -> String {
+        let mut rustfmt = Command::new("rustfmt")
+            .arg("--edition=2018")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("The snapshot tests need rustfmt");
+        rustfmt
+            .stdin
+            .take()
+            .expect("rustfmt has no stdin")
+            .write_all(tokens.to_string().as_bytes())
+            .expect("Could not write to rustfmt");
+        let output = rustfmt
+            .wait_with_output()
+            .expect("Could not read from rustfmt");
+        assert!(output.status.success(), "rustfmt failed on {}", tokens);
+        String::from_utf8(output.stdout).expect("rustfmt returned invalid UTF-8")
+    }
+
+    #[test]
+    fn no_hint() {
+        assert_snapshot(
+            "no_hint",
+            parse_quote! {
+                struct Player {
+                    health: i64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn without_metadata() {
+        assert_snapshot_with_args(
+            "without_metadata",
+            &parse_quote! { extends = gdnative::api::Node },
+            parse_quote! {
+                struct Player {
+                    health: i64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn no_export() {
+        assert_snapshot(
+            "no_export",
+            parse_quote! {
+                struct Player {
+                    #[no_export]
+                    velocity: Vector2,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_default() {
+        assert_snapshot(
+            "export_default",
+            parse_quote! {
+                struct Player {
+                    #[export]
+                    #[default(100)]
+                    health: i64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_conversion() {
+        assert_snapshot(
+            "export_conversion",
+            parse_quote! {
+                struct Player {
+                    #[export(as = f64, from = Health, into = Health::raw)]
+                    #[export_range(0.0, 100.0)]
+                    #[default(Health(100.0))]
+                    health: Health,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_range() {
+        assert_snapshot(
+            "export_range",
+            parse_quote! {
+                struct Player {
+                    #[export_range(1, 10, 2, "or_greater")]
+                    level: i64,
+                    #[export_range(0.0, 1.0, 0.1, "or_lesser")]
+                    volume: f64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_exp_range() {
+        assert_snapshot(
+            "export_exp_range",
+            parse_quote! {
+                struct Player {
+                    #[export_exp_range(1.0, 1000.0)]
+                    zoom: f64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_enum() {
+        assert_snapshot(
+            "export_enum",
+            parse_quote! {
+                struct Player {
+                    #[export_enum("Idle", "Run", "Jump")]
+                    state: i64,
+                    #[export_enum("Red", "Blue")]
+                    team: GodotString,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_file() {
+        assert_snapshot(
+            "export_file",
+            parse_quote! {
+                struct Player {
+                    #[export_file("*.png")]
+                    texture: GodotString,
+                    #[export_file]
+                    save: GodotString,
+                    #[export_global_file("*.cfg")]
+                    config: GodotString,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_dir() {
+        assert_snapshot(
+            "export_dir",
+            parse_quote! {
+                struct Player {
+                    #[export_dir]
+                    levels: GodotString,
+                    #[export_global_dir]
+                    saves: GodotString,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_string_hints() {
+        assert_snapshot(
+            "export_string_hints",
+            parse_quote! {
+                struct Player {
+                    #[export_multiline]
+                    description: GodotString,
+                    #[export_color_no_alpha]
+                    color: Color,
+                    #[export_node_path(Camera)]
+                    camera: NodePath,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn export_flags() {
+        assert_snapshot(
+            "export_flags",
+            parse_quote! {
+                struct Player {
+                    #[export_flags("Fire", "Water", "Earth")]
+                    elements: i64,
+                    #[export_flags_2d_physics]
+                    layers_2d_physics: i64,
+                    #[export_flags_2d_render]
+                    layers_2d_render: i64,
+                    #[export_flags_3d_physics]
+                    layers_3d_physics: i64,
+                    #[export_flags_3d_render]
+                    layers_3d_render: i64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn signals() {
+        assert_snapshot(
+            "signals",
+            parse_quote! {
+                #[signal(hit(damage: I64, source: GodotString = "unknown"))]
+                #[signal(died())]
+                struct Player {
+                    health: i64,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn constants() {
+        assert_snapshot(
+            "constants",
+            parse_quote! {
+                #[constant(MAX_HEALTH: i64 = 100)]
+                #[constant(State { IDLE, RUN, JUMP = 10 })]
+                #[constant(Animation { IDLE, WALK })]
+                struct Player {
+                    health: i64,
+                }
+            },
+        );
     }
 }
@@ -1,11 +1,12 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream, Result};
-use syn::parse_macro_input::parse;
 use syn::punctuated::Punctuated;
-use syn::{parenthesized, token, Expr, Field, ItemStruct, Lit, LitStr, Token, Type};
+use syn::{parenthesized, parse2, token, Expr, Field, ItemStruct, Lit, LitStr, Token, Type};
 
 mod kw {
     syn::custom_keyword!(export);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(into);
     syn::custom_keyword!(or_greater);
     syn::custom_keyword!(or_lesser);
     syn::custom_keyword!(var);
@@ -185,11 +186,56 @@ impl Parse for ExportFlags {
     }
 }
 
+/// `#[export(as = GodotType, from = fn(GodotType) -> T, into = fn(&T) -> GodotType)]`. Used to
+/// export fields whose type does not implement `Export`.
+pub struct ExportConversion {
+    pub ty: Type,
+    pub from: Option<Expr>,
+    pub into: Option<Expr>,
+}
+
+impl Parse for ExportConversion {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        let _paren_token = parenthesized!(content in input);
+        let mut ty = None;
+        let mut from = None;
+        let mut into = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(Token![as]) {
+                let _as = content.parse::<Token![as]>()?;
+                let _eq = content.parse::<Token![=]>()?;
+                ty = Some(content.parse()?);
+            } else if lookahead.peek(kw::from) {
+                let _from = content.parse::<kw::from>()?;
+                let _eq = content.parse::<Token![=]>()?;
+                from = Some(content.parse()?);
+            } else if lookahead.peek(kw::into) {
+                let _into = content.parse::<kw::into>()?;
+                let _eq = content.parse::<Token![=]>()?;
+                into = Some(content.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !content.is_empty() {
+                let _comma = content.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self {
+            ty: ty.ok_or_else(|| content.error("Expected `as = Type` in export"))?,
+            from,
+            into,
+        })
+    }
+}
+
 pub struct Property {
     pub name: Ident,
     pub ty: Type,
     pub export_type: ExportType,
     pub default: Option<Expr>,
+    pub conversion: Option<ExportConversion>,
 }
 
 impl Property {
@@ -199,8 +245,14 @@ impl Property {
             ty,
             export_type: ExportType::NoHint,
             default: None,
+            conversion: None,
         }
     }
+
+    /// The type the property is registered with in Godot.
+    pub fn export_ty(&self) -> &Type {
+        self.conversion.as_ref().map_or(&self.ty, |x| &x.ty)
+    }
 }
 
 struct DefaultProperty {
@@ -240,46 +292,54 @@ pub fn get_property(item: &mut Field) -> Property {
                 .expect("Expected valid attr on property")
                 .to_string();
             let mut should_filter = false;
-            let tokens = x.tokens.clone().into();
+            let tokens = x.tokens.clone();
             match ident.as_str() {
                 "default" => {
-                    let default = parse::<DefaultProperty>(tokens)
+                    let default = parse2::<DefaultProperty>(tokens)
                         .expect("Invalid params for default")
                         .expr;
                     property.default = Some(default);
                 }
-                "export" => property.export_type = ExportType::Export,
+                "export" if x.tokens.is_empty() => property.export_type = ExportType::Export,
+                "export" => {
+                    let conversion = parse2(tokens).expect("Invalid conversion on export");
+                    property.conversion = Some(conversion);
+                    // Allows a hint like `#[export_range]` to be applied to the converted type.
+                    if let ExportType::NoHint = property.export_type {
+                        property.export_type = ExportType::Export;
+                    }
+                }
                 "no_export" => property.export_type = ExportType::NoExport,
                 "export_range" => {
-                    let range = parse(tokens).expect("Invalid range on export_range");
+                    let range = parse2(tokens).expect("Invalid range on export_range");
                     property.export_type = ExportType::ExportRange(range);
                 }
                 "export_enum" => {
-                    let export_enum = parse::<ExportEnum>(tokens).expect("Invalid exportenum");
+                    let export_enum = parse2::<ExportEnum>(tokens).expect("Invalid exportenum");
                     property.export_type = ExportType::ExportEnum(export_enum);
                 }
                 "export_file" => {
-                    let export_file = parse(tokens).expect("Invalid export_file");
+                    let export_file = parse2(tokens).expect("Invalid export_file");
                     property.export_type = ExportType::ExportFile(export_file)
                 }
                 "export_dir" => property.export_type = ExportType::ExportDir,
                 "export_global_file" => {
-                    let export_global_file = parse(tokens).expect("Invalid export_file");
+                    let export_global_file = parse2(tokens).expect("Invalid export_file");
                     property.export_type = ExportType::ExportGlobalFile(export_global_file)
                 }
                 "export_global_dir" => property.export_type = ExportType::ExportGlobalDir,
                 "export_multiline" => property.export_type = ExportType::ExportMultiline,
                 "export_exp_range" => {
-                    let range = parse(tokens).expect("Invalid range on export_exp_range");
+                    let range = parse2(tokens).expect("Invalid range on export_exp_range");
                     property.export_type = ExportType::ExportExpRange(range);
                 }
                 "export_color_no_alpha" => property.export_type = ExportType::ExportColorNoAlpha,
                 "export_flags" => {
-                    let flags = parse(tokens).expect("Invalid export_flags");
+                    let flags = parse2(tokens).expect("Invalid export_flags");
                     property.export_type = ExportType::ExportFlags(flags)
                 }
                 "export_node_path" => {
-                    let types = parse(tokens).expect("Invalid args for export_node_path");
+                    let types = parse2(tokens).expect("Invalid args for export_node_path");
                     property.export_type = ExportType::ExportNodePath(types)
                 }
                 "export_flags_2d_physics" => {
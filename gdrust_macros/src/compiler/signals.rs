@@ -13,8 +13,7 @@ pub fn extract_signals(item: &mut ItemStruct) -> Vec<SignalDecl> {
                 .get_ident()
                 .filter(|x| *x == "signal")
                 .map(|_x| {
-                    let tokens: proc_macro::TokenStream = attr.tokens.clone().into();
-                    let signal = syn::parse_macro_input::parse::<SignalWithParens>(tokens)
+                    let signal = syn::parse2::<SignalWithParens>(attr.tokens.clone())
                         .expect("Could not parse signal declaration")
                         .signal;
                     result.push(signal);
@@ -0,0 +1,98 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    health: i64,
+}
+impl Player {
+    pub const MAX_HEALTH: i64 = 100;
+    pub const STATE_IDLE: i64 = 0;
+    pub const STATE_RUN: i64 = Self::STATE_IDLE + 1;
+    pub const STATE_JUMP: i64 = 10;
+    pub const ANIMATION_IDLE: i64 = 0;
+    pub const ANIMATION_WALK: i64 = Self::ANIMATION_IDLE + 1;
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            health: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("health")
+            .with_ref_getter(|this, _owner| &this.health)
+            .with_setter(|this, _owner, val| this.health = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<i64>("MAX_HEALTH")
+            .with_getter(|_this, _owner| Self::MAX_HEALTH)
+            .with_usage(gdnative::nativescript::PropertyUsage::empty())
+            .done();
+        builder
+            .add_property::<gdnative::core_types::Dictionary>("State")
+            .with_getter(|_this, _owner| {
+                let dict = gdnative::core_types::Dictionary::new();
+                dict.insert("IDLE", Self::STATE_IDLE);
+                dict.insert("RUN", Self::STATE_RUN);
+                dict.insert("JUMP", Self::STATE_JUMP);
+                dict.into_shared()
+            })
+            .with_usage(gdnative::nativescript::PropertyUsage::empty())
+            .done();
+        builder
+            .add_property::<gdnative::core_types::Dictionary>("Animation")
+            .with_getter(|_this, _owner| {
+                let dict = gdnative::core_types::Dictionary::new();
+                dict.insert("IDLE", Self::ANIMATION_IDLE);
+                dict.insert("WALK", Self::ANIMATION_WALK);
+                dict.into_shared()
+            })
+            .with_usage(gdnative::nativescript::PropertyUsage::empty())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "health",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::None,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "MAX_HEALTH",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::None,
+                    default: Some("100"),
+                    usage: gdnative::nativescript::PropertyUsage::empty(),
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "State",
+                    rust_type: "Dictionary",
+                    export_type: "Dictionary",
+                    hint: gdrust::metadata::PropertyHint::None,
+                    default: Some("{ IDLE: 0, RUN: 1, JUMP: 10 }"),
+                    usage: gdnative::nativescript::PropertyUsage::empty(),
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "Animation",
+                    rust_type: "Dictionary",
+                    export_type: "Dictionary",
+                    hint: gdrust::metadata::PropertyHint::None,
+                    default: Some("{ IDLE: 0, WALK: 1 }"),
+                    usage: gdnative::nativescript::PropertyUsage::empty(),
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,49 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    health: Health,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            health: Health(100.0),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<f64>("health")
+            .with_hint(gdnative::nativescript::init::property::FloatHint::Range(
+                gdnative::nativescript::property::RangeHint::new(0.0.into(), 100.0.into()),
+            ))
+            .with_getter(|this, _owner| (Health::raw)(&this.health))
+            .with_setter(|this, _owner, val: f64| this.health = (Health)(val))
+            .with_default((Health::raw)(&Health(100.0)))
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[gdrust::metadata::PropertyMetadata {
+                name: "health",
+                rust_type: "Health",
+                export_type: "f64",
+                hint: gdrust::metadata::PropertyHint::Range(gdrust::metadata::RangeHint {
+                    min: 0.0,
+                    max: 100.0,
+                    step: None,
+                    or_lesser: false,
+                    or_greater: false,
+                }),
+                default: Some("Health(100.0)"),
+                usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+            }],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    health: i64,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self { health: 100 }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("health")
+            .with_ref_getter(|this, _owner| &this.health)
+            .with_setter(|this, _owner, val| this.health = val)
+            .with_default(100)
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[gdrust::metadata::PropertyMetadata {
+                name: "health",
+                rust_type: "i64",
+                export_type: "i64",
+                hint: gdrust::metadata::PropertyHint::None,
+                default: Some("100"),
+                usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+            }],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,60 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    levels: GodotString,
+    saves: GodotString,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            levels: Default::default(),
+            saves: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<GodotString>("levels")
+            .with_hint(gdnative::nativescript::init::property::StringHint::Dir)
+            .with_ref_getter(|this, _owner| &this.levels)
+            .with_setter(|this, _owner, val| this.levels = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<GodotString>("saves")
+            .with_hint(gdnative::nativescript::init::property::StringHint::GlobalDir)
+            .with_ref_getter(|this, _owner| &this.saves)
+            .with_setter(|this, _owner, val| this.saves = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "levels",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::Dir,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "saves",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::GlobalDir,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,71 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    state: i64,
+    team: GodotString,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            state: Default::default(),
+            team: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("state")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Enum(
+                gdnative::nativescript::init::property::EnumHint::new(vec![
+                    "Idle".into(),
+                    "Run".into(),
+                    "Jump".into(),
+                ]),
+            ))
+            .with_ref_getter(|this, _owner| &this.state)
+            .with_setter(|this, _owner, val| this.state = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<GodotString>("team")
+            .with_hint(gdnative::nativescript::init::property::StringHint::Enum(
+                gdnative::nativescript::init::property::EnumHint::new(vec![
+                    "Red".into(),
+                    "Blue".into(),
+                ]),
+            ))
+            .with_ref_getter(|this, _owner| &this.team)
+            .with_setter(|this, _owner, val| this.team = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "state",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Enum(&["Idle", "Run", "Jump"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "team",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::Enum(&["Red", "Blue"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,49 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    zoom: f64,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            zoom: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<f64>("zoom")
+            .with_hint(gdnative::nativescript::init::property::FloatHint::ExpRange(
+                gdnative::nativescript::property::RangeHint::new(1.0.into(), 1000.0.into()),
+            ))
+            .with_ref_getter(|this, _owner| &this.zoom)
+            .with_setter(|this, _owner, val| this.zoom = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[gdrust::metadata::PropertyMetadata {
+                name: "zoom",
+                rust_type: "f64",
+                export_type: "f64",
+                hint: gdrust::metadata::PropertyHint::ExpRange(gdrust::metadata::RangeHint {
+                    min: 1.0,
+                    max: 1000.0,
+                    step: None,
+                    or_lesser: false,
+                    or_greater: false,
+                }),
+                default: None,
+                usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+            }],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,85 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    texture: GodotString,
+    save: GodotString,
+    config: GodotString,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            texture: Default::default(),
+            save: Default::default(),
+            config: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<GodotString>("texture")
+            .with_hint(gdnative::nativescript::init::property::StringHint::File(
+                gdnative::nativescript::init::property::EnumHint::new(vec!["*.png".into()]),
+            ))
+            .with_ref_getter(|this, _owner| &this.texture)
+            .with_setter(|this, _owner, val| this.texture = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<GodotString>("save")
+            .with_hint(gdnative::nativescript::init::property::StringHint::File(
+                gdnative::nativescript::init::property::EnumHint::new(vec![]),
+            ))
+            .with_ref_getter(|this, _owner| &this.save)
+            .with_setter(|this, _owner, val| this.save = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<GodotString>("config")
+            .with_hint(
+                gdnative::nativescript::init::property::StringHint::GlobalFile(
+                    gdnative::nativescript::init::property::EnumHint::new(vec!["*.cfg".into()]),
+                ),
+            )
+            .with_ref_getter(|this, _owner| &this.config)
+            .with_setter(|this, _owner, val| this.config = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "texture",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::File(&["*.png"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "save",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::File(&[]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "config",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::GlobalFile(&["*.cfg"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
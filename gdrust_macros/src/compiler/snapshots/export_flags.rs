@@ -0,0 +1,117 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    elements: i64,
+    layers_2d_physics: i64,
+    layers_2d_render: i64,
+    layers_3d_physics: i64,
+    layers_3d_render: i64,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            elements: Default::default(),
+            layers_2d_physics: Default::default(),
+            layers_2d_render: Default::default(),
+            layers_3d_physics: Default::default(),
+            layers_3d_render: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("elements")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Flags(
+                gdnative::nativescript::init::property::EnumHint::new(vec![
+                    "Fire".into(),
+                    "Water".into(),
+                    "Earth".into(),
+                ]),
+            ))
+            .with_ref_getter(|this, _owner| &this.elements)
+            .with_setter(|this, _owner, val| this.elements = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<i64>("layers_2d_physics")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Layers2DPhysics)
+            .with_ref_getter(|this, _owner| &this.layers_2d_physics)
+            .with_setter(|this, _owner, val| this.layers_2d_physics = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<i64>("layers_2d_render")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Layers2DRender)
+            .with_ref_getter(|this, _owner| &this.layers_2d_render)
+            .with_setter(|this, _owner, val| this.layers_2d_render = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<i64>("layers_3d_physics")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Layers3DPhysics)
+            .with_ref_getter(|this, _owner| &this.layers_3d_physics)
+            .with_setter(|this, _owner, val| this.layers_3d_physics = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<i64>("layers_3d_render")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Layers3DRender)
+            .with_ref_getter(|this, _owner| &this.layers_3d_render)
+            .with_setter(|this, _owner, val| this.layers_3d_render = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "elements",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Flags(&["Fire", "Water", "Earth"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "layers_2d_physics",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Layers2DPhysics,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "layers_2d_render",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Layers2DRender,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "layers_3d_physics",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Layers3DPhysics,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "layers_3d_render",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Layers3DRender,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
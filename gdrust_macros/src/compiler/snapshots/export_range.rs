@@ -0,0 +1,80 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    level: i64,
+    volume: f64,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            level: Default::default(),
+            volume: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("level")
+            .with_hint(gdnative::nativescript::init::property::IntHint::Range(
+                gdnative::nativescript::property::RangeHint::new(1, 10)
+                    .with_step(2)
+                    .or_greater(),
+            ))
+            .with_ref_getter(|this, _owner| &this.level)
+            .with_setter(|this, _owner, val| this.level = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<f64>("volume")
+            .with_hint(gdnative::nativescript::init::property::FloatHint::Range(
+                gdnative::nativescript::property::RangeHint::new(0.0.into(), 1.0.into())
+                    .with_step(0.1)
+                    .or_lesser(),
+            ))
+            .with_ref_getter(|this, _owner| &this.volume)
+            .with_setter(|this, _owner, val| this.volume = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "level",
+                    rust_type: "i64",
+                    export_type: "i64",
+                    hint: gdrust::metadata::PropertyHint::Range(gdrust::metadata::RangeHint {
+                        min: 1.0,
+                        max: 10.0,
+                        step: Some(2.0),
+                        or_lesser: false,
+                        or_greater: true,
+                    }),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "volume",
+                    rust_type: "f64",
+                    export_type: "f64",
+                    hint: gdrust::metadata::PropertyHint::Range(gdrust::metadata::RangeHint {
+                        min: 0.0,
+                        max: 1.0,
+                        step: Some(0.1),
+                        or_lesser: true,
+                        or_greater: false,
+                    }),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,76 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    description: GodotString,
+    color: Color,
+    camera: NodePath,
+}
+impl Player {
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            description: Default::default(),
+            color: Default::default(),
+            camera: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<GodotString>("description")
+            .with_hint(gdnative::nativescript::init::property::StringHint::Multiline)
+            .with_ref_getter(|this, _owner| &this.description)
+            .with_setter(|this, _owner, val| this.description = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<Color>("color")
+            .with_hint(gdnative::nativescript::init::property::ColorHint::NoAlpha)
+            .with_ref_getter(|this, _owner| &this.color)
+            .with_setter(|this, _owner, val| this.color = val)
+            .with_default(Default::default())
+            .done();
+        builder
+            .add_property::<NodePath>("camera")
+            .with_ref_getter(|this, _owner| &this.camera)
+            .with_setter(|this, _owner, val| this.camera = val)
+            .with_default(Default::default())
+            .done();
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[
+                gdrust::metadata::PropertyMetadata {
+                    name: "description",
+                    rust_type: "GodotString",
+                    export_type: "GodotString",
+                    hint: gdrust::metadata::PropertyHint::Multiline,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "color",
+                    rust_type: "Color",
+                    export_type: "Color",
+                    hint: gdrust::metadata::PropertyHint::ColorNoAlpha,
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdrust::metadata::PropertyMetadata {
+                    name: "camera",
+                    rust_type: "NodePath",
+                    export_type: "NodePath",
+                    hint: gdrust::metadata::PropertyHint::NodePath(&["Camera"]),
+                    default: None,
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+            signals: &[],
+        };
+        METADATA
+    }
+}
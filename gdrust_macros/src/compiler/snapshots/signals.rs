@@ -0,0 +1,87 @@
+#[derive(gdnative :: NativeClass)]
+#[inherit(gdnative::api::Node)]
+#[register_with(Self::__register_properties_and_signals)]
+struct Player {
+    health: i64,
+}
+impl Player {
+    pub const HIT: &'static str = "hit";
+    pub const DIED: &'static str = "died";
+    #[allow(clippy::default_trait_access)]
+    fn new(_owner: gdnative::prelude::TRef<gdnative::api::Node>) -> Self {
+        Self {
+            health: Default::default(),
+        }
+    }
+    #[allow(clippy::default_trait_access)]
+    fn __register_properties_and_signals(builder: &gdnative::prelude::ClassBuilder<Self>) {
+        builder
+            .add_property::<i64>("health")
+            .with_ref_getter(|this, _owner| &this.health)
+            .with_setter(|this, _owner, val| this.health = val)
+            .with_default(Default::default())
+            .done();
+        builder.add_signal(gdnative::nativescript::Signal {
+            name: "hit",
+            args: &[
+                gdnative::nativescript::SignalArgument {
+                    name: "damage",
+                    default: gdnative::core_types::Variant::new(),
+                    export_info: gdnative::nativescript::ExportInfo::new(
+                        gdnative::core_types::VariantType::I64,
+                    ),
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+                gdnative::nativescript::SignalArgument {
+                    name: "source",
+                    default: gdnative::core_types::ToVariant::to_variant(&"unknown"),
+                    export_info: gdnative::nativescript::ExportInfo::new(
+                        gdnative::core_types::VariantType::GodotString,
+                    ),
+                    usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+                },
+            ],
+        });
+        builder.add_signal(gdnative::nativescript::Signal {
+            name: "died",
+            args: &[],
+        });
+    }
+    #[doc = r" Describes what this class registers with Godot, without needing a running engine."]
+    pub fn __gdrust_metadata() -> gdrust::metadata::ClassMetadata {
+        const METADATA: gdrust::metadata::ClassMetadata = gdrust::metadata::ClassMetadata {
+            name: "Player",
+            base: "gdnative::api::Node",
+            properties: &[gdrust::metadata::PropertyMetadata {
+                name: "health",
+                rust_type: "i64",
+                export_type: "i64",
+                hint: gdrust::metadata::PropertyHint::None,
+                default: None,
+                usage: gdnative::nativescript::PropertyUsage::DEFAULT,
+            }],
+            signals: &[
+                gdrust::metadata::SignalMetadata {
+                    name: "hit",
+                    args: &[
+                        gdrust::metadata::SignalArgMetadata {
+                            name: "damage",
+                            ty: "I64",
+                            default: None,
+                        },
+                        gdrust::metadata::SignalArgMetadata {
+                            name: "source",
+                            ty: "GodotString",
+                            default: Some("\"unknown\""),
+                        },
+                    ],
+                },
+                gdrust::metadata::SignalMetadata {
+                    name: "died",
+                    args: &[],
+                },
+            ],
+        };
+        METADATA
+    }
+}
@@ -0,0 +1,236 @@
+use crate::preload::project_dir;
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, Token};
+
+/// The actions every Godot project has, even if they are not in `project.godot`.
+const BUILT_IN_ACTIONS: &[&str] = &[
+    "ui_accept",
+    "ui_select",
+    "ui_cancel",
+    "ui_focus_next",
+    "ui_focus_prev",
+    "ui_left",
+    "ui_right",
+    "ui_up",
+    "ui_down",
+    "ui_page_up",
+    "ui_page_down",
+    "ui_home",
+    "ui_end",
+];
+
+mod kw {
+    syn::custom_keyword!(name);
+    syn::custom_keyword!(unchecked);
+}
+
+enum ActionArg {
+    Name(LitStr),
+    Unchecked,
+}
+
+impl Parse for ActionArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::unchecked) {
+            input.parse::<kw::unchecked>()?;
+            Ok(Self::Unchecked)
+        } else {
+            input.parse::<kw::name>()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self::Name(input.parse()?))
+        }
+    }
+}
+
+/// The arguments of every `#[action(...)]` on an enum or variant.
+#[derive(Default)]
+struct ActionArgs {
+    name: Option<LitStr>,
+    unchecked: bool,
+}
+
+impl ActionArgs {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|x| x.path.is_ident("action")) {
+            let args =
+                attr.parse_args_with(Punctuated::<ActionArg, Token![,]>::parse_terminated)?;
+            for arg in args {
+                match arg {
+                    ActionArg::Name(x) => result.name = Some(x),
+                    ActionArg::Unchecked => result.unchecked = true,
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+pub(crate) fn derive_input_actions(input: DeriveInput) -> TokenStream {
+    match compile(input) {
+        Ok(x) => x,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn compile(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = match input.data {
+        Data::Enum(x) => x,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "InputActions can only be derived for enums",
+            ))
+        }
+    };
+    let enum_args = ActionArgs::from_attrs(&input.attrs)?;
+    if let Some(name) = enum_args.name {
+        return Err(syn::Error::new(
+            name.span(),
+            "`name` can only be used on a variant",
+        ));
+    }
+    let input_map = if enum_args.unchecked {
+        None
+    } else {
+        InputMap::find()
+    };
+
+    let ident = input.ident;
+    let mut variants = Vec::new();
+    let mut names = Vec::new();
+    for variant in data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "InputActions variants can not have fields",
+            ));
+        }
+        let ActionArgs { name, unchecked } = ActionArgs::from_attrs(&variant.attrs)?;
+        let name = name.unwrap_or_else(|| {
+            LitStr::new(
+                &variant.ident.to_string().to_snake_case(),
+                variant.ident.span(),
+            )
+        });
+        if let Some(input_map) = input_map.as_ref().filter(|_| !unchecked) {
+            input_map.check(&name)?;
+        }
+        variants.push(variant.ident);
+        names.push(name);
+    }
+
+    // Including project.godot makes cargo rebuild when the input map changes.
+    let rebuild = input_map.map(|x| {
+        let file = x.file;
+        quote! { const _: &[u8] = include_bytes!(#file); }
+    });
+    Ok(quote! {
+        impl gdrust::input::InputActions for #ident {
+            fn action_name(&self) -> &'static str {
+                #rebuild
+                match self {
+                    #(#ident::#variants => #names,)*
+                }
+            }
+
+            fn all() -> &'static [Self] {
+                &[#(#ident::#variants),*]
+            }
+        }
+    })
+}
+
+/// The actions in the `[input]` section of `project.godot`.
+struct InputMap {
+    file: String,
+    actions: Vec<String>,
+}
+
+impl InputMap {
+    /// Reads the input map of the Godot project, if there is one. See `project_dir`.
+    fn find() -> Option<Self> {
+        let file = project_dir().ok()?.join("project.godot");
+        let contents = std::fs::read_to_string(&file).ok()?;
+        Some(Self {
+            file: file.to_string_lossy().to_string(),
+            actions: input_actions(&contents),
+        })
+    }
+
+    fn check(&self, name: &LitStr) -> syn::Result<()> {
+        let value = name.value();
+        if BUILT_IN_ACTIONS.contains(&value.as_str()) || self.actions.contains(&value) {
+            return Ok(());
+        }
+        Err(syn::Error::new(
+            name.span(),
+            format!(
+                "There is no \"{}\" action in the input map of {}. Add it in Project Settings > Input Map, or mark it #[action(unchecked)] if it is added at runtime",
+                value,
+                Path::new(&self.file).display()
+            ),
+        ))
+    }
+}
+
+/// Reads the action names out of the `[input]` section. Each action is a `name={` line, followed by
+/// its events over several lines.
+fn input_actions(project: &str) -> Vec<String> {
+    project
+        .lines()
+        .skip_while(|x| x.trim() != "[input]")
+        .skip(1)
+        .take_while(|x| !(x.starts_with('[') && x.trim_end().ends_with(']')))
+        .filter_map(|x| x.split_once('='))
+        .map(|(name, _)| name)
+        .filter(|x| {
+            !x.is_empty()
+                && x.chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::input_actions;
+
+    #[test]
+    fn reads_input_section() {
+        let project = r#"config_version=4
+
+[application]
+
+config/name="example"
+
+[input]
+
+jump={
+"deadzone": 0.5,
+"events": [ Object(InputEventKey,"resource_local_to_scene":false,"scancode":32,"unicode":0,"echo":false,"script":null)
+ ]
+}
+move_left={
+"deadzone": 0.5,
+"events": [  ]
+}
+
+[rendering]
+
+environment/default_environment="res://default_env.tres"
+"#;
+        assert_eq!(input_actions(project), vec!["jump", "move_left"]);
+    }
+
+    #[test]
+    fn no_input_section() {
+        assert!(input_actions("config_version=4\n").is_empty());
+    }
+}
@@ -1,11 +1,16 @@
 use proc_macro::TokenStream;
 mod compiler;
+mod input_actions;
+mod preload;
 
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{parse_quote, ItemStruct, Result, Token, Type};
 
 mod kw {
     syn::custom_keyword!(extends);
+    syn::custom_keyword!(debug_expand);
+    syn::custom_keyword!(metadata);
 }
 
 pub(crate) struct Extends {
@@ -21,13 +26,80 @@ impl Parse for Extends {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
+enum GdrustArg {
+    Extends(Extends),
+    DebugExpand,
+    Metadata,
+}
+
+impl Parse for GdrustArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(kw::debug_expand) {
+            let _debug_expand = input.parse::<kw::debug_expand>()?;
+            Ok(Self::DebugExpand)
+        } else if input.peek(kw::metadata) {
+            let _metadata = input.parse::<kw::metadata>()?;
+            Ok(Self::Metadata)
+        } else {
+            Ok(Self::Extends(input.parse()?))
+        }
+    }
+}
+
+pub(crate) struct GdrustArgs {
+    extends: Extends,
+    debug_expand: bool,
+    metadata: bool,
+}
+
+impl Parse for GdrustArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut extends = None;
+        let mut debug_expand = false;
+        let mut metadata = false;
+        for arg in Punctuated::<GdrustArg, Token![,]>::parse_terminated(input)? {
+            match arg {
+                GdrustArg::Extends(x) => extends = Some(x),
+                GdrustArg::DebugExpand => debug_expand = true,
+                GdrustArg::Metadata => metadata = true,
+            }
+        }
+        Ok(Self {
+            extends: extends.unwrap_or(Extends {
+                ty: parse_quote! { gdnative::api::Object },
+            }),
+            debug_expand,
+            metadata,
+        })
+    }
+}
+
 #[proc_macro_attribute]
 pub fn gdrust(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut parsed = syn::parse_macro_input!(item as ItemStruct);
-    let extends = syn::parse_macro_input::parse::<Extends>(attr).unwrap_or(Extends {
-        ty: parse_quote! { gdnative::api::Object },
-    });
-    let compiled = compiler::compile(&mut parsed, &extends);
-    // println!("{}", compiled.to_string());
+    let args = syn::parse_macro_input!(attr as GdrustArgs);
+    let compiled = compiler::compile(&mut parsed, &args);
     compiled.into()
 }
+
+/// Loads a resource like GdScript's `preload`, but when the line runs. The path is checked at
+/// compile time against the Godot project, which is found by walking up from the crate to the
+/// first `project.godot`. Set `GDRUST_PROJECT_DIR` to use a different directory. The type is
+/// inferred from the extension, and can be given explicitly with
+/// `preload!("res://frames.tres" as SpriteFrames)`.
+#[proc_macro]
+pub fn preload(input: TokenStream) -> TokenStream {
+    let parsed = syn::parse_macro_input!(input as preload::Preload);
+    preload::preload(parsed).into()
+}
+
+/// Implements `gdrust::input::InputActions` for an enum of input actions. Each variant is the snake
+/// case of its name, and can be renamed with `#[action(name = "ui_accept")]`. The names are checked
+/// against the input map of the Godot project (found the same way as `preload!`) unless the variant
+/// or enum is marked `#[action(unchecked)]`.
+#[proc_macro_derive(InputActions, attributes(action))]
+pub fn derive_input_actions(input: TokenStream) -> TokenStream {
+    let parsed = syn::parse_macro_input!(input as syn::DeriveInput);
+    input_actions::derive_input_actions(parsed).into()
+}
@@ -0,0 +1,270 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token, Type};
+
+/// The environment variable which overrides the directory `res://` paths are resolved against.
+const PROJECT_DIR_VAR: &str = "GDRUST_PROJECT_DIR";
+
+pub(crate) struct Preload {
+    path: LitStr,
+    ty: Option<(Token![as], Type)>,
+}
+
+impl Parse for Preload {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let ty = if input.peek(Token![as]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+        Ok(Self { path, ty })
+    }
+}
+
+pub(crate) fn preload(input: Preload) -> TokenStream {
+    match compile(input) {
+        Ok(x) => x,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn compile(input: Preload) -> syn::Result<TokenStream> {
+    let path = input.path.value();
+    let relative = path.strip_prefix("res://").ok_or_else(|| {
+        syn::Error::new(
+            input.path.span(),
+            format!("Expected a path starting with res://, but got \"{}\"", path),
+        )
+    })?;
+    let root = project_dir().map_err(|e| syn::Error::new(input.path.span(), e))?;
+    let file = root.join(relative);
+    if !file.is_file() {
+        return Err(syn::Error::new(
+            input.path.span(),
+            format!("{} does not exist. Looked for {}", path, file.display()),
+        ));
+    }
+    let ty = if let Some((_, ty)) = input.ty {
+        quote! { #ty }
+    } else {
+        let class = infer_class(&file).ok_or_else(|| {
+            syn::Error::new(
+                input.path.span(),
+                format!(
+                    "Could not work out the resource type of {}. Specify it with `preload!(\"{}\" as Type)`",
+                    path, path
+                ),
+            )
+        })?;
+        let class = format_ident!("{}", class);
+        quote! { gdnative::api::#class }
+    };
+    // Including a file makes cargo rebuild when it is moved or deleted.
+    let track = tracked_file(&file).map(|x| {
+        let x = x.to_string_lossy().to_string();
+        quote! { const _: &[u8] = include_bytes!(#x); }
+    });
+    // The `Ref` is not kept, since a static would only drop it after Godot has shut down. Godot
+    // caches the resource while anything still uses it.
+    Ok(quote! {
+        {
+            #track
+            gdrust::unsafe_functions::resource_loader_ext::ResourceLoaderExt::expect_load::<#ty>(
+                gdnative::api::ResourceLoader::godot_singleton(),
+                #path,
+            )
+        }
+    })
+}
+
+/// The file to include so cargo notices when `file` is moved or deleted. Imported assets can be
+/// large, so their small `.import` file is used instead. Other binary files are not tracked.
+fn tracked_file(file: &Path) -> Option<PathBuf> {
+    let mut import = file.as_os_str().to_owned();
+    import.push(".import");
+    let import = PathBuf::from(import);
+    if import.is_file() {
+        return Some(import);
+    }
+    let extension = file.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "tres" | "tscn" | "escn" | "gd" | "shader" => Some(file.to_path_buf()),
+        _ => None,
+    }
+}
+
+/// Finds the Godot project by walking up from the crate to the first directory with a
+/// `project.godot`, unless `GDRUST_PROJECT_DIR` is set.
+pub(crate) fn project_dir() -> Result<PathBuf, String> {
+    project_dir_from(
+        std::env::var_os(PROJECT_DIR_VAR),
+        std::env::var_os("CARGO_MANIFEST_DIR"),
+    )
+}
+
+/// `project_dir`, with the values of `GDRUST_PROJECT_DIR` and `CARGO_MANIFEST_DIR`.
+fn project_dir_from(
+    project_dir: Option<OsString>,
+    manifest_dir: Option<OsString>,
+) -> Result<PathBuf, String> {
+    if let Some(dir) = project_dir {
+        return Ok(PathBuf::from(dir));
+    }
+    let manifest_dir = manifest_dir
+        .map(PathBuf::from)
+        .ok_or_else(|| "CARGO_MANIFEST_DIR is not set".to_string())?;
+    manifest_dir
+        .ancestors()
+        .find(|x| x.join("project.godot").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            format!(
+                "Could not find project.godot in {} or any of its parents. Set {} to the Godot project directory",
+                manifest_dir.display(),
+                PROJECT_DIR_VAR
+            )
+        })
+}
+
+/// Works out the class a resource is loaded as from its extension. Text resources have their
+/// class in the header, so that is used instead.
+fn infer_class(file: &Path) -> Option<String> {
+    let extension = file.extension()?.to_str()?.to_lowercase();
+    let class = match extension.as_str() {
+        "tscn" | "scn" | "escn" | "gltf" | "glb" | "dae" => "PackedScene",
+        "png" | "jpg" | "jpeg" | "webp" | "svg" | "bmp" | "tga" | "hdr" | "exr" => "Texture",
+        "wav" | "ogg" | "mp3" => "AudioStream",
+        "obj" | "mesh" => "Mesh",
+        "material" => "Material",
+        "shader" => "Shader",
+        "ttf" | "otf" | "woff" => "DynamicFontData",
+        "gd" => "GDScript",
+        "tres" => return text_resource_class(file),
+        "res" => "Resource",
+        _ => return None,
+    };
+    Some(class.to_string())
+}
+
+/// Reads the class out of a `[gd_resource type="Class" ...]` header.
+fn text_resource_class(file: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(file).ok()?;
+    let header = contents.lines().next()?.strip_prefix("[gd_resource ")?;
+    let class = header.split("type=\"").nth(1)?.split('"').next()?;
+    Some(class.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{infer_class, project_dir_from, text_resource_class, tracked_file};
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    /// A new empty directory in the temporary directory, unique to this test and process.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("gdrust_preload_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)
+            .unwrap_or_else(|e| panic!("Could not create {}: {}", dir.display(), e));
+        dir
+    }
+
+    /// Writes `contents` to `dir/name` and returns its path.
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents)
+            .unwrap_or_else(|e| panic!("Could not write {}: {}", path.display(), e));
+        path
+    }
+
+    #[test]
+    fn infer_class_from_extension() {
+        let class = |x: &str| infer_class(Path::new(x));
+        assert_eq!(class("res://main.tscn").as_deref(), Some("PackedScene"));
+        assert_eq!(class("res://icon.PNG").as_deref(), Some("Texture"));
+        assert_eq!(class("res://jump.ogg").as_deref(), Some("AudioStream"));
+        assert_eq!(class("res://player.gd").as_deref(), Some("GDScript"));
+        assert_eq!(class("res://data.json"), None);
+        assert_eq!(class("res://README"), None);
+    }
+
+    #[test]
+    fn infer_class_of_text_resource() {
+        let dir = temp_dir("infer_class");
+        let file = write(
+            &dir,
+            "frames.tres",
+            "[gd_resource type=\"SpriteFrames\" load_steps=2 format=2]\n",
+        );
+        assert_eq!(infer_class(&file).as_deref(), Some("SpriteFrames"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn text_resource_class_from_header() {
+        let dir = temp_dir("header");
+        let file = write(
+            &dir,
+            "material.tres",
+            "[gd_resource type=\"SpatialMaterial\" format=2]\n\n[resource]\n",
+        );
+        assert_eq!(
+            text_resource_class(&file).as_deref(),
+            Some("SpatialMaterial")
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn text_resource_class_without_header() {
+        let dir = temp_dir("no_header");
+        let file = write(
+            &dir,
+            "no_header.tres",
+            "[resource]\nalbedo_color = Color( 1, 0, 0, 1 )\n",
+        );
+        assert_eq!(text_resource_class(&file), None);
+        let file = write(&dir, "empty.tres", "");
+        assert_eq!(text_resource_class(&file), None);
+        assert_eq!(text_resource_class(&dir.join("missing.tres")), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tracked_file_prefers_import() {
+        let dir = temp_dir("tracked");
+        let texture = write(&dir, "icon.png", "");
+        let import = write(&dir, "icon.png.import", "");
+        let scene = write(&dir, "main.tscn", "");
+        let sound = write(&dir, "jump.ogg", "");
+        assert_eq!(tracked_file(&texture), Some(import));
+        assert_eq!(tracked_file(&scene), Some(scene));
+        assert_eq!(tracked_file(&sound), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn project_dir_override() {
+        let dir = project_dir_from(
+            Some(OsString::from("/games/example")),
+            Some(OsString::from("/games/other/rust")),
+        );
+        assert_eq!(dir, Ok(PathBuf::from("/games/example")));
+    }
+
+    #[test]
+    fn project_dir_above_manifest() {
+        let project = temp_dir("project");
+        write(&project, "project.godot", "");
+        let manifest = project.join("rust");
+        std::fs::create_dir_all(&manifest).unwrap();
+        let dir = project_dir_from(None, Some(manifest.into_os_string()));
+        assert_eq!(dir, Ok(project.clone()));
+        std::fs::remove_dir_all(&project).unwrap();
+    }
+}
@@ -0,0 +1,7 @@
+/// Each file in `tests/ui` misuses `#[gdrust]`, and must fail with the error in the `.stderr` file
+/// next to it. Run with `TRYBUILD=overwrite` to update the `.stderr` files after changing an error.
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+#[constant(MAX_HEALTH i64 = 100)]
+struct Player {
+    health: i64,
+}
+
+fn main() {}
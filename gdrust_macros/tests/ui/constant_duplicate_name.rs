@@ -0,0 +1,10 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+#[constant(STATE_IDLE: i64 = 1)]
+#[constant(State { IDLE, RUN })]
+struct Player {
+    health: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[default = 100]
+    health: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export(f64, from = Health)]
+    health: Health,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_enum(1, 2, 3)]
+    state: i64,
+}
+
+fn main() {}
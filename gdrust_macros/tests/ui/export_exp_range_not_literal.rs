@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_exp_range(0.0, max)]
+    speed: f64,
+}
+
+fn main() {}
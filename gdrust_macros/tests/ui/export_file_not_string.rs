@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_file(png)]
+    texture: GodotString,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_flags(1, 2, 4)]
+    flags: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_range(0, MAX_HEALTH)]
+    health: i64,
+}
+
+fn main() {}
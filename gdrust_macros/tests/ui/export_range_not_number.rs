@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[export_range(0, 10)]
+    name: GodotString,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player {
+    #[serde::rename = "hp"]
+    health: i64,
+}
+
+fn main() {}
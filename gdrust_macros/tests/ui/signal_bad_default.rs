@@ -0,0 +1,9 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+#[signal(hit(damage: I64 =))]
+struct Player {
+    health: i64,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use gdrust_macros::gdrust;
+
+#[gdrust(extends = gdnative::api::Node)]
+struct Player(i64);
+
+fn main() {}